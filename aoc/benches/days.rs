@@ -0,0 +1,38 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+// Benchmark parsing and both parts of a day separately, on the input bundled with the day
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, input: &str) {
+    let solution = S::parse(input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day_{day}"));
+    // Keep the slow days from dominating the run time
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(input)).unwrap());
+    });
+    group.bench_function("part1", |b| b.iter(|| black_box(&solution).part1()));
+    group.bench_function("part2", |b| b.iter(|| black_box(&solution).part2()));
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_1::CalibrationDocument>(c, 1, day_1::DEFAULT_INPUT);
+    bench_day::<day_2::GameRecord>(c, 2, day_2::DEFAULT_INPUT);
+    bench_day::<day_3::Schematic>(c, 3, day_3::DEFAULT_INPUT);
+    bench_day::<day_4::Deck>(c, 4, day_4::DEFAULT_INPUT);
+    bench_day::<day_5::Almanac>(c, 5, day_5::DEFAULT_INPUT);
+    bench_day::<day_6::RaceSheet>(c, 6, day_6::DEFAULT_INPUT);
+    bench_day::<day_7::CamelCards>(c, 7, day_7::DEFAULT_INPUT);
+    bench_day::<day_8::Network>(c, 8, day_8::DEFAULT_INPUT);
+    bench_day::<day_9::Report>(c, 9, day_9::DEFAULT_INPUT);
+    bench_day::<day_10::Map>(c, 10, day_10::DEFAULT_INPUT);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
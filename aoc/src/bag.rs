@@ -0,0 +1,17 @@
+use day_2::GameRecord;
+
+// Print the ids of the day 2 games that could have been played with the bag, given as e.g.
+// `12 red, 13 green, 14 blue`
+pub fn possible_games(bag: &str, path: Option<&str>) -> Result<(), String> {
+    let bag = day_2::parse_variant(bag).map_err(|err| err.diagnostic(bag))?;
+
+    let input = common::read_input(path, day_2::DEFAULT_INPUT).map_err(|err| err.to_string())?;
+    let record =
+        GameRecord::parse_with_colors(&input, None).map_err(|err| err.diagnostic(&input))?;
+
+    for game in record.possible_games(&bag) {
+        println!("{}", game.id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use common::Solution;
+use std::time::Instant;
+
+// Run the solution of the given day, reading the input from `path` or the day's bundled input
+pub fn run(day: u8, part: Option<u8>, path: Option<&str>) -> Result<(), String> {
+    match day {
+        1 => run_solution::<day_1::CalibrationDocument>(day, part, path, day_1::DEFAULT_INPUT),
+        2 => run_solution::<day_2::GameRecord>(day, part, path, day_2::DEFAULT_INPUT),
+        3 => run_solution::<day_3::Schematic>(day, part, path, day_3::DEFAULT_INPUT),
+        4 => run_solution::<day_4::Deck>(day, part, path, day_4::DEFAULT_INPUT),
+        5 => run_solution::<day_5::Almanac>(day, part, path, day_5::DEFAULT_INPUT),
+        6 => run_solution::<day_6::RaceSheet>(day, part, path, day_6::DEFAULT_INPUT),
+        7 => run_solution::<day_7::CamelCards>(day, part, path, day_7::DEFAULT_INPUT),
+        8 => run_solution::<day_8::Network>(day, part, path, day_8::DEFAULT_INPUT),
+        9 => run_solution::<day_9::Report>(day, part, path, day_9::DEFAULT_INPUT),
+        10 => run_solution::<day_10::Map>(day, part, path, day_10::DEFAULT_INPUT),
+        _ => Err(format!("Day {day} has not been solved")),
+    }
+}
+
+fn run_solution<S: Solution>(
+    day: u8,
+    part: Option<u8>,
+    path: Option<&str>,
+    default_input: &str,
+) -> Result<(), String> {
+    let input = common::read_input(path, default_input).map_err(|err| err.to_string())?;
+
+    println!("Day {day}");
+
+    let start = Instant::now();
+    let solution = S::parse(&input).map_err(|err| err.diagnostic(&input))?;
+    println!("  Parse: {:?}", start.elapsed());
+
+    if part.is_none_or(|part| part == 1) {
+        let start = Instant::now();
+        let answer = solution.part1().map_err(|err| err.to_string())?;
+        println!("  Part 1: {answer} ({:?})", start.elapsed());
+    }
+
+    if part.is_none_or(|part| part == 2) {
+        let start = Instant::now();
+        let answer = solution.part2().map_err(|err| err.to_string())?;
+        println!("  Part 2: {answer} ({:?})", start.elapsed());
+    }
+
+    Ok(())
+}
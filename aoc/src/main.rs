@@ -0,0 +1,55 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+mod bag;
+mod days;
+
+use clap::{Parser, Subcommand};
+use std::process;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a day and print the answers with timings
+    Run {
+        /// The day to run
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Path to the puzzle input, or `-` to read stdin. Defaults to the input bundled with the day
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Print the ids of the day 2 games that could have been played with a bag
+    PossibleGames {
+        /// The cubes in the bag, e.g. "12 red, 13 green, 14 blue"
+        bag: String,
+
+        /// Path to the game record, or `-` to read stdin. Defaults to the input bundled with day 2
+        #[arg(long)]
+        input: Option<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => days::run(day, part, input.as_deref()),
+        Command::PossibleGames { bag, input } => bag::possible_games(&bag, input.as_deref()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,219 @@
+use std::error;
+use std::fmt::{self, Display, Formatter};
+
+/// The position of the offending text in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The line number, starting at 1
+    pub line: usize,
+    /// The column in characters, starting at 1
+    pub column: usize,
+    /// The text that could not be parsed
+    pub text: String,
+}
+
+impl Location {
+    /// The location of `text` within `line`, which must be a subslice of it.
+    ///
+    /// Parsers of a single line report line 1, callers move the error to the right line with
+    /// [`ParseError::at_line`].
+    #[must_use]
+    pub fn of(line: &str, text: &str) -> Self {
+        let offset = (text.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        debug_assert!(
+            offset + text.len() <= line.len(),
+            "text is not part of the line"
+        );
+
+        Self {
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+        }
+    }
+
+    /// The location just past the end of `line`
+    #[must_use]
+    pub fn end_of(line: &str) -> Self {
+        Self::of(line, &line[line.len()..])
+    }
+}
+
+/// An error found while parsing the puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text is not a valid number
+    InvalidNumber(Location),
+    /// Something other than what was expected was found
+    Unexpected {
+        expected: &'static str,
+        location: Location,
+    },
+    /// The input ended before what was expected was found
+    UnexpectedEnd { expected: &'static str, line: usize },
+}
+
+impl ParseError {
+    /// Shorthand for [`ParseError::Unexpected`]
+    #[must_use]
+    pub fn unexpected(expected: &'static str, location: Location) -> Self {
+        Self::Unexpected { expected, location }
+    }
+
+    /// Move the error to the given line, for errors returned from parsing a single line
+    #[must_use]
+    pub fn at_line(mut self, line: usize) -> Self {
+        match &mut self {
+            Self::InvalidNumber(location) | Self::Unexpected { location, .. } => {
+                location.line = line;
+            }
+            Self::UnexpectedEnd { line: end_line, .. } => *end_line = line,
+        }
+        self
+    }
+
+    /// The location of the offending text, if the error has one
+    #[must_use]
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Self::InvalidNumber(location) | Self::Unexpected { location, .. } => Some(location),
+            Self::UnexpectedEnd { .. } => None,
+        }
+    }
+
+    /// The line number of the error, starting at 1
+    #[must_use]
+    pub fn line(&self) -> usize {
+        match self {
+            Self::InvalidNumber(location) | Self::Unexpected { location, .. } => location.line,
+            Self::UnexpectedEnd { line, .. } => *line,
+        }
+    }
+
+    /// A diagnostic for the user, showing the error together with the offending line of the
+    /// input and a marker under the offending text
+    #[must_use]
+    pub fn diagnostic(&self, input: &str) -> String {
+        let diagnostic = format!("error: {self}");
+
+        let Some(location) = self.location() else {
+            return diagnostic;
+        };
+        let Some(line) = input.lines().nth(location.line - 1) else {
+            return diagnostic;
+        };
+
+        let line_nr = location.line.to_string();
+        let padding = " ".repeat(line_nr.len());
+        let marker = "^".repeat(location.text.chars().count().max(1));
+        let indent = " ".repeat(location.column - 1);
+
+        format!("{diagnostic}\n{padding} |\n{line_nr} | {line}\n{padding} | {indent}{marker}")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber(location) => write!(
+                f,
+                "line {}, column {}: invalid number `{}`",
+                location.line, location.column, location.text
+            ),
+            Self::Unexpected { expected, location } if location.text.is_empty() => write!(
+                f,
+                "line {}, column {}: expected {expected}, found the end of the line",
+                location.line, location.column
+            ),
+            Self::Unexpected { expected, location } => write!(
+                f,
+                "line {}, column {}: expected {expected}, found `{}`",
+                location.line, location.column, location.text
+            ),
+            Self::UnexpectedEnd { expected, line } => {
+                write!(
+                    f,
+                    "line {line}: expected {expected}, found the end of the input"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// An error found while solving a part of the puzzle from an input that parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// A value is too large for the type it is computed in, such as `the sum of the part
+    /// numbers`
+    Overflow(String),
+    /// The input describes something the puzzle cannot be solved for
+    Unsolvable(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow(what) => write!(f, "{what} is too large to compute"),
+            Self::Unsolvable(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl error::Error for SolveError {}
+
+/// Parse `text`, a subslice of `line`, as a number.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidNumber`] if the text is not a valid number.
+pub fn parse_number<T: std::str::FromStr>(line: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::InvalidNumber(Location::of(line, text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_of() {
+        let line = "Game 12: 3 blue";
+        let location = Location::of(line, &line[9..10]);
+        assert_eq!(location.line, 1);
+        assert_eq!(location.column, 10);
+        assert_eq!(location.text, "3");
+
+        let line = "ä ö 7";
+        let location = Location::of(line, &line[6..]);
+        assert_eq!(location.column, 5);
+
+        let location = Location::end_of("abc");
+        assert_eq!(location.column, 4);
+        assert_eq!(location.text, "");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "1 2 3\n4 x 6\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = parse_number::<u32>(line, &line[2..3])
+            .unwrap_err()
+            .at_line(2);
+        assert_eq!(err.to_string(), "line 2, column 3: invalid number `x`");
+        assert_eq!(
+            err.diagnostic(input),
+            "error: line 2, column 3: invalid number `x`\n  |\n2 | 4 x 6\n  |   ^"
+        );
+
+        let err = ParseError::UnexpectedEnd {
+            expected: "a `Distance:` line",
+            line: 2,
+        };
+        assert_eq!(
+            err.diagnostic(input),
+            "error: line 2: expected a `Distance:` line, found the end of the input"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::{error, fs};
+
+/// Read the puzzle input from the given path, or from stdin if the path is `-`.
+/// Without a path the `default` input, usually bundled with the binary, is returned.
+///
+/// # Errors
+///
+/// Returns an [`InputError`] if the file or stdin could not be read.
+pub fn read_input(path: Option<&str>, default: &str) -> Result<String, InputError> {
+    let Some(path) = path else {
+        return Ok(default.to_string());
+    };
+
+    let result = if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|source| InputError {
+        path: path.to_string(),
+        source,
+    })
+}
+
+/// The puzzle input could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    /// The path that was read, `-` for stdin
+    pub path: String,
+    source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.path == "-" {
+            write!(f, "Could not read input from stdin: {}", self.source)
+        } else {
+            write!(
+                f,
+                "Could not read input from {}: {}",
+                self.path, self.source
+            )
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        let default = "1 2 3\n";
+        assert_eq!(read_input(None, default).unwrap(), default);
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let input = read_input(Some(path), default).unwrap();
+        assert!(input.starts_with("[package]"));
+
+        let err = read_input(Some("does/not/exist.txt"), default).unwrap_err();
+        assert_eq!(err.path, "does/not/exist.txt");
+        assert!(err
+            .to_string()
+            .starts_with("Could not read input from does/not/exist.txt"));
+    }
+}
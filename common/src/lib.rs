@@ -0,0 +1,9 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+mod error;
+mod input;
+mod solution;
+
+pub use error::{parse_number, Location, ParseError, SolveError};
+pub use input::{read_input, InputError};
+pub use solution::{Solution, Unsolved};
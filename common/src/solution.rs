@@ -0,0 +1,44 @@
+use crate::{ParseError, SolveError};
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The solution to one day of the puzzle.
+///
+/// The input is parsed once into the implementing type, after which both
+/// parts are answered from the parsed representation.
+pub trait Solution: Sized {
+    /// The answer to part 1
+    type Part1: Debug + Display + PartialEq;
+    /// The answer to part 2
+    type Part2: Debug + Display + PartialEq;
+
+    /// Parse the puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] pointing at the offending text if the input is malformed.
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    /// Solve part 1 of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SolveError`] if the answer cannot be computed from the parsed input.
+    fn part1(&self) -> Result<Self::Part1, SolveError>;
+
+    /// Solve part 2 of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SolveError`] if the answer cannot be computed from the parsed input.
+    fn part2(&self) -> Result<Self::Part2, SolveError>;
+}
+
+/// The answer to a part that has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved")
+    }
+}
@@ -0,0 +1,376 @@
+//! Day 1: Trebuchet?!
+//!
+//! Recover the calibration values from the lines of the calibration document.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+pub mod scanner;
+
+pub use scanner::DigitScanner;
+
+use common::{Location, ParseError, Solution, SolveError};
+use std::fmt;
+use std::sync::LazyLock;
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The calibration document, one calibration value per line
+#[derive(Debug, Clone)]
+pub struct CalibrationDocument {
+    lines: Vec<String>,
+}
+
+/// Which tokens count as digits when decoding a calibration value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodingMode {
+    /// Only the digits `0` to `9`
+    Digits,
+    /// The digits and their English names, `zero` to `nine`
+    DigitsAndWords,
+}
+
+impl DecodingMode {
+    /// Every mode, in the order of the parts of the puzzle
+    pub const ALL: [DecodingMode; 2] = [DecodingMode::Digits, DecodingMode::DigitsAndWords];
+
+    /// The scanner finding the tokens of this mode
+    #[must_use]
+    pub fn scanner(self) -> &'static DigitScanner {
+        match self {
+            DecodingMode::Digits => &DIGITS,
+            DecodingMode::DigitsAndWords => &ENGLISH,
+        }
+    }
+}
+
+impl fmt::Display for DecodingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodingMode::Digits => write!(f, "digits"),
+            DecodingMode::DigitsAndWords => write!(f, "digits and words"),
+        }
+    }
+}
+
+/// The calibration values of a document decoded with one mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration {
+    /// The calibration value of each line, `None` for lines without a digit
+    pub values: Vec<Option<u32>>,
+    /// Sum of the calibration values of the lines with a digit
+    pub sum: u32,
+    /// An error for each line without a digit, in the order of the lines
+    pub diagnostics: Vec<ParseError>,
+}
+
+impl CalibrationDocument {
+    /// Decode the calibration value of every line with the given mode. Lines without a digit
+    /// are skipped and reported in the diagnostics, unless `strict` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first line without a digit if `strict` is set.
+    pub fn calibrate(&self, mode: DecodingMode, strict: bool) -> Result<Calibration, ParseError> {
+        self.calibrate_with(mode.scanner(), strict)
+    }
+
+    /// Decode the calibration value of every line with the tokens of the given scanner, as
+    /// [`CalibrationDocument::calibrate`] does for a mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first line without a token if `strict` is set.
+    pub fn calibrate_with(
+        &self,
+        scanner: &DigitScanner,
+        strict: bool,
+    ) -> Result<Calibration, ParseError> {
+        let mut calibration = Calibration {
+            values: Vec::with_capacity(self.lines.len()),
+            sum: 0,
+            diagnostics: Vec::new(),
+        };
+
+        for (i, line) in self.lines.iter().enumerate() {
+            let value = calibration_value_with(line, scanner);
+            if let Some(value) = value {
+                calibration.sum += value;
+            } else {
+                let err =
+                    ParseError::unexpected("a digit", Location::of(line, line)).at_line(i + 1);
+                if strict {
+                    return Err(err);
+                }
+                calibration.diagnostics.push(err);
+            }
+            calibration.values.push(value);
+        }
+
+        Ok(calibration)
+    }
+
+    /// Sum of the calibration values decoded with the given mode. Lines without a digit in
+    /// that mode are left out.
+    #[must_use]
+    pub fn calibration_sum(&self, mode: DecodingMode) -> u32 {
+        self.calibration_sum_with(mode.scanner())
+    }
+
+    /// Sum of the calibration values decoded with the tokens of the given scanner. Lines
+    /// without a token are left out.
+    #[must_use]
+    pub fn calibration_sum_with(&self, scanner: &DigitScanner) -> u32 {
+        self.lines
+            .iter()
+            .filter_map(|line| calibration_value_with(line, scanner))
+            .sum()
+    }
+}
+
+/// The calibration value of a line: its first and last digit forming a two digit number.
+/// `None` if the line has no digit in the given mode.
+#[must_use]
+pub fn calibration_value(line: &str, mode: DecodingMode) -> Option<u32> {
+    calibration_value_with(line, mode.scanner())
+}
+
+/// The calibration value of a line from the first and last token found by the scanner. `None`
+/// if the line has no token.
+#[must_use]
+pub fn calibration_value_with(line: &str, scanner: &DigitScanner) -> Option<u32> {
+    let (first, last) = scanner.first_and_last(line)?;
+    Some(first * 10 + last)
+}
+
+impl Solution for CalibrationDocument {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    // Lines without a digit are kept, see `CalibrationDocument::calibrate`
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: input.lines().map(String::from).collect(),
+        })
+    }
+
+    // Sum of the calibration values, counting only the digits
+    fn part1(&self) -> Result<u32, SolveError> {
+        Ok(self.calibration_sum(DecodingMode::Digits))
+    }
+
+    // Sum of the calibration values, where digits may also be spelled out
+    fn part2(&self) -> Result<u32, SolveError> {
+        Ok(self.calibration_sum(DecodingMode::DigitsAndWords))
+    }
+}
+
+/// The spelled out names of the digits, indexed by their value
+pub const INT_NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// The scanners of the decoding modes, shared by all lines
+static DIGITS: LazyLock<DigitScanner> = LazyLock::new(DigitScanner::digits);
+static ENGLISH: LazyLock<DigitScanner> = LazyLock::new(DigitScanner::english);
+
+/// The first digit in the line, either as a digit or spelled out, or `None` if the line contains
+/// no digit.
+#[must_use]
+pub fn get_first_integer(line: &str) -> Option<u32> {
+    ENGLISH.first_and_last(line).map(|(first, _)| first)
+}
+
+/// The last digit in the line, either as a digit or spelled out, or `None` if the line contains
+/// no digit.
+#[must_use]
+pub fn get_last_integer(line: &str) -> Option<u32> {
+    ENGLISH.first_and_last(line).map(|(_, last)| last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_get_first_integer() {
+        assert_eq!(get_first_integer("one"), Some(1));
+        assert_eq!(get_first_integer("jkkjtwoghthreeghgh"), Some(2));
+        assert_eq!(get_first_integer("jfdkj4hdj"), Some(4));
+        assert_eq!(get_first_integer("hej1hej2hej"), Some(1));
+    }
+
+    #[test]
+    fn test_get_last_integer() {
+        assert_eq!(get_last_integer("one"), Some(1));
+        assert_eq!(get_last_integer("jkkjtwoghthreeghgh"), Some(3));
+        assert_eq!(get_last_integer("jfdkj4hdj"), Some(4));
+        assert_eq!(get_last_integer("hej1hej2hej"), Some(2));
+        assert_eq!(get_last_integer("abc"), None);
+    }
+
+    #[test]
+    fn test_calibration_value() {
+        // The line, and its value with digits only and with digits and words
+        let corpus = [
+            ("1abc2", Some(12), Some(12)),
+            ("treb7uchet", Some(77), Some(77)),
+            ("7", Some(77), Some(77)),
+            ("two1nine", Some(11), Some(29)),
+            ("eightwo", None, Some(82)),
+            ("eightwothree", None, Some(83)),
+            ("oneight", None, Some(18)),
+            ("twone", None, Some(21)),
+            ("sevenine", None, Some(79)),
+            ("threeight", None, Some(38)),
+            ("zoneight234", Some(24), Some(14)),
+            ("7pqrstsixteen", Some(77), Some(76)),
+            ("nineeightseven2", Some(22), Some(92)),
+            ("0zero", Some(0), Some(0)),
+            ("onetwothreefourfivesixseveneightnine", None, Some(19)),
+            ("ninine", None, Some(99)),
+            ("ONE1", Some(11), Some(11)),
+            ("", None, None),
+            ("abcdef", None, None),
+        ];
+
+        for (line, digits, words) in corpus {
+            assert_eq!(
+                calibration_value(line, DecodingMode::Digits),
+                digits,
+                "{line}"
+            );
+            assert_eq!(
+                calibration_value(line, DecodingMode::DigitsAndWords),
+                words,
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let document = CalibrationDocument::parse("1abc2\nabc\ntwo3\n\n").unwrap();
+
+        let calibration = document.calibrate(DecodingMode::Digits, false).unwrap();
+        assert_eq!(calibration.values, vec![Some(12), None, Some(33), None]);
+        assert_eq!(calibration.sum, 45);
+        assert_eq!(
+            calibration
+                .diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "line 2, column 1: expected a digit, found `abc`",
+                "line 4, column 1: expected a digit, found the end of the line",
+            ]
+        );
+
+        let calibration = document
+            .calibrate(DecodingMode::DigitsAndWords, false)
+            .unwrap();
+        assert_eq!(calibration.sum, 12 + 23);
+        assert_eq!(calibration.diagnostics.len(), 2);
+
+        let err = document.calibrate(DecodingMode::Digits, true).unwrap_err();
+        assert_eq!(err.line(), 2);
+
+        let document = CalibrationDocument::parse("1abc2\ntwo3").unwrap();
+        let calibration = document
+            .calibrate(DecodingMode::DigitsAndWords, true)
+            .unwrap();
+        assert_eq!(calibration.sum, 35);
+        assert!(calibration.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let french = DigitScanner::new([("un", 1), ("deux", 2), ("trois", 3), ("neuf", 9)]);
+        assert_eq!(calibration_value_with("deux5neuf", &french), Some(29));
+        assert_eq!(calibration_value_with("troisix", &french), Some(33));
+        assert_eq!(calibration_value_with("two", &french), None);
+
+        let document = CalibrationDocument::parse("un2\nxtroisx\nsix").unwrap();
+        assert_eq!(document.calibration_sum_with(&french), 12 + 33);
+        let calibration = document.calibrate_with(&french, false).unwrap();
+        assert_eq!(calibration.values, vec![Some(12), Some(33), None]);
+        assert_eq!(
+            calibration.diagnostics[0].to_string(),
+            "line 3, column 1: expected a digit, found `six`"
+        );
+        let err = document.calibrate_with(&french, true).unwrap_err();
+        assert_eq!(err.line(), 3);
+    }
+
+    #[test]
+    fn test_unicode() {
+        assert_eq!(calibration_value("ö1ä", DecodingMode::Digits), Some(11));
+        assert_eq!(
+            calibration_value("日本two語", DecodingMode::DigitsAndWords),
+            Some(22)
+        );
+        assert_eq!(
+            calibration_value("🦀eight🦀🦀2🦀", DecodingMode::DigitsAndWords),
+            Some(82)
+        );
+        // Non-ASCII digits are not digits
+        assert_eq!(calibration_value("٣", DecodingMode::Digits), None);
+    }
+
+    // A digit or digit name, with its value
+    fn token() -> impl Strategy<Value = (String, u32)> {
+        (0..10_u32, any::<bool>()).prop_map(|(value, spelled)| {
+            if spelled {
+                (INT_NAMES[value as usize].to_string(), value)
+            } else {
+                (value.to_string(), value)
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_tokens_in_noise(
+            tokens in prop::collection::vec((token(), "[^0-9a-zA-Z]{0,8}"), 1..6),
+            prefix in "[^0-9a-zA-Z]{0,8}",
+        ) {
+            let mut line = prefix;
+            for ((token, _), noise) in &tokens {
+                line.push_str(token);
+                line.push_str(noise);
+            }
+
+            let first = tokens[0].0 .1;
+            let last = tokens[tokens.len() - 1].0 .1;
+            prop_assert_eq!(
+                calibration_value(&line, DecodingMode::DigitsAndWords),
+                Some(first * 10 + last)
+            );
+        }
+
+        #[test]
+        fn prop_digits_in_any_line(line in any::<String>()) {
+            let digits: Vec<u32> = line
+                .chars()
+                .filter(char::is_ascii_digit)
+                .filter_map(|c| c.to_digit(10))
+                .collect();
+            let expected = digits.first().zip(digits.last()).map(|(first, last)| first * 10 + last);
+            prop_assert_eq!(calibration_value(&line, DecodingMode::Digits), expected);
+        }
+
+        #[test]
+        fn prop_calibrate_any_input(input in any::<String>()) {
+            let document = CalibrationDocument::parse(&input).unwrap();
+            for mode in DecodingMode::ALL {
+                let calibration = document.calibrate(mode, false).unwrap();
+                prop_assert_eq!(calibration.values.len(), input.lines().count());
+                for err in &calibration.diagnostics {
+                    let _ = err.diagnostic(&input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use aho_corasick::AhoCorasick;
+
+/// A scanner finding the first and last digit tokens of a line in a single pass.
+///
+/// The tokens are the digits `0` to `9` and any words of the vocabulary, each with the value
+/// it stands for. Tokens may overlap, so `eightwo` holds both `eight` and `two`.
+#[derive(Debug, Clone)]
+pub struct DigitScanner {
+    automaton: AhoCorasick,
+    // The value of each pattern of the automaton, by pattern index
+    values: Vec<u32>,
+}
+
+impl DigitScanner {
+    /// A scanner for the digits and the given words with their values, e.g. `("ten", 10)`.
+    /// Empty words are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vocabulary is too large to build the automaton from.
+    #[must_use]
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let (patterns, values): (Vec<&str>, Vec<u32>) = digits
+            .into_iter()
+            .zip(0..)
+            .chain(words.into_iter().filter(|(word, _)| !word.is_empty()))
+            .unzip();
+
+        Self {
+            automaton: AhoCorasick::new(patterns).expect("vocabulary too large"),
+            values,
+        }
+    }
+
+    /// A scanner for the digits only
+    #[must_use]
+    pub fn digits() -> Self {
+        Self::new([])
+    }
+
+    /// A scanner for the digits and the English names of the digits, `zero` to `nine`
+    #[must_use]
+    pub fn english() -> Self {
+        Self::new(crate::INT_NAMES.into_iter().zip(0..))
+    }
+
+    /// The values of the first and last token in the line, `None` if it has no tokens. Of
+    /// tokens starting at the same position, the longest is used.
+    #[must_use]
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        // Matches are reported in order of where they end, so track the starts explicitly
+        let mut first: Option<Token> = None;
+        let mut last: Option<Token> = None;
+
+        for found in self.automaton.find_overlapping_iter(line) {
+            let token = Token {
+                start: found.start(),
+                len: found.len(),
+                value: self.values[found.pattern().as_usize()],
+            };
+            if first.is_none_or(|first| (token.start, first.len) < (first.start, token.len)) {
+                first = Some(token);
+            }
+            if last.is_none_or(|last| (token.start, token.len) > (last.start, last.len)) {
+                last = Some(token);
+            }
+        }
+
+        Some((first?.value, last?.value))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    start: usize,
+    len: usize,
+    value: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_and_last() {
+        let scanner = DigitScanner::english();
+        assert_eq!(scanner.first_and_last("two1nine"), Some((2, 9)));
+        assert_eq!(scanner.first_and_last("eightwo"), Some((8, 2)));
+        assert_eq!(scanner.first_and_last("7pqrstsixteen"), Some((7, 6)));
+        assert_eq!(scanner.first_and_last("treb7uchet"), Some((7, 7)));
+        assert_eq!(scanner.first_and_last("abc"), None);
+
+        let scanner = DigitScanner::digits();
+        assert_eq!(scanner.first_and_last("two1nine"), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let teens = [("ten", 10), ("eleven", 11), ("sixteen", 16), ("six", 6)];
+        let scanner = DigitScanner::new(teens);
+        assert_eq!(scanner.first_and_last("xsixteenx"), Some((16, 16)));
+        assert_eq!(scanner.first_and_last("ten3eleven"), Some((10, 11)));
+
+        let swedish = [("ett", 1), ("två", 2), ("tre", 3)];
+        let scanner = DigitScanner::new(swedish);
+        assert_eq!(scanner.first_and_last("tvåsju5ettre"), Some((2, 3)));
+    }
+}
@@ -0,0 +1,18 @@
+use common::Solution;
+use day_1::CalibrationDocument;
+
+const EXAMPLE_1: &str = include_str!("../src/input_test_1.txt");
+const EXAMPLE_2: &str = include_str!("../src/input_test_2.txt");
+
+#[test]
+fn example_1() {
+    let document = CalibrationDocument::parse(EXAMPLE_1).unwrap();
+    assert_eq!(document.part1(), Ok(142));
+    assert_eq!(document.part2(), Ok(142));
+}
+
+#[test]
+fn example_2() {
+    let document = CalibrationDocument::parse(EXAMPLE_2).unwrap();
+    assert_eq!(document.part2(), Ok(281));
+}
@@ -0,0 +1,34 @@
+//! Day 10: Pipe Maze
+//!
+//! Follow the loop of pipes through the maze from the starting tile.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+pub mod pipe_map;
+
+pub use crate::pipe_map::{Direction, Map, Pipe, WalkError};
+
+use crate::pipe_map::parse_map;
+use common::{ParseError, Solution, SolveError, Unsolved};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+impl Solution for Map {
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_map(input)
+    }
+
+    // Number of steps to the point in the loop farthest from the start
+    fn part1(&self) -> Result<usize, SolveError> {
+        self.steps_to_farthest()
+            .map_err(|err| SolveError::Unsolvable(err.to_string()))
+    }
+
+    fn part2(&self) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+}
@@ -1,7 +1,10 @@
+use common::{Location, ParseError};
 use std::fmt::{self, Display, Formatter};
 
+/// The tiles of the maze, `None` for ground
 pub struct Map {
     map: Vec<Vec<Option<Pipe>>>,
+    /// The `(x, y)` position of the starting tile
     pub start: (usize, usize),
 }
 
@@ -21,6 +24,8 @@ impl Display for Map {
 }
 
 impl Map {
+    /// The pipe at the given position, `None` for ground or positions outside the map
+    #[must_use]
     pub fn get(&self, x: usize, y: usize) -> Option<&Pipe> {
         self.map
             .get(y)
@@ -28,29 +33,31 @@ impl Map {
             .and_then(|tile| tile.as_ref())
     }
 
+    /// The directions from the start towards the pipes connected to it
+    #[must_use]
     pub fn get_first_directions(&self) -> Vec<Direction> {
         let (x, y) = self.start;
         let mut directions = Vec::new();
         if y > 0 {
             if let Some(tile) = self.get(x, y - 1) {
-                if tile.can_connect(&Direction::South) {
+                if tile.can_connect(Direction::South) {
                     directions.push(Direction::North);
                 }
             }
         }
         if let Some(tile) = self.get(x + 1, y) {
-            if tile.can_connect(&Direction::West) {
+            if tile.can_connect(Direction::West) {
                 directions.push(Direction::East);
             }
         }
         if let Some(tile) = self.get(x, y + 1) {
-            if tile.can_connect(&Direction::North) {
+            if tile.can_connect(Direction::North) {
                 directions.push(Direction::South);
             }
         }
         if x > 0 {
             if let Some(tile) = self.get(x - 1, y) {
-                if tile.can_connect(&Direction::East) {
+                if tile.can_connect(Direction::East) {
                     directions.push(Direction::West);
                 }
             }
@@ -58,50 +65,158 @@ impl Map {
         directions
     }
 
-    pub fn step(&self, (x, y): (usize, usize), direction: &Direction) -> (usize, usize) {
+    /// The position one step from the given position in the given direction
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn step(&self, (x, y): (usize, usize), direction: Direction) -> (usize, usize) {
         let (dx, dy) = direction.offset();
-        let nx = x as isize + dx;
-        let ny = y as isize + dy;
-        (nx as usize, ny as usize)
+        (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy))
     }
+
+    // Follow the pipe entered by stepping from the position in the direction, returning the
+    // position of the pipe and the direction it leaves in
+    fn follow(
+        &self,
+        from: (usize, usize),
+        direction: Direction,
+    ) -> Result<((usize, usize), Direction), WalkError> {
+        let (dx, dy) = direction.offset();
+        let position = match (from.0.checked_add_signed(dx), from.1.checked_add_signed(dy)) {
+            (Some(x), Some(y)) if self.map.get(y).is_some_and(|row| x < row.len()) => (x, y),
+            _ => return Err(WalkError::LeftMap { from, direction }),
+        };
+        let out = self
+            .get(position.0, position.1)
+            .and_then(|pipe| pipe.out_direction(direction))
+            .ok_or(WalkError::Broken { at: position })?;
+        Ok((position, out))
+    }
+
+    /// The number of steps along the loop to the point farthest from the start, found by walking
+    /// the loop in both directions until the two walks meet
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WalkError`] if the start is not connected to two pipes, or if the loop leaves
+    /// the map or runs into a tile that does not continue it.
+    pub fn steps_to_farthest(&self) -> Result<usize, WalkError> {
+        let &[mut dir_1, mut dir_2] = self.get_first_directions().as_slice() else {
+            return Err(WalkError::Start { at: self.start });
+        };
+        let mut pos_1 = self.start;
+        let mut pos_2 = self.start;
+
+        let mut steps = 0;
+        loop {
+            steps += 1;
+            (pos_1, dir_1) = self.follow(pos_1, dir_1)?;
+            (pos_2, dir_2) = self.follow(pos_2, dir_2)?;
+            if pos_1 == pos_2 {
+                return Ok(steps);
+            }
+        }
+    }
+}
+
+/// Why the loop could not be followed from the start
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkError {
+    /// The start is not connected to exactly two pipes
+    Start { at: (usize, usize) },
+    /// A step leads off the map
+    LeftMap {
+        from: (usize, usize),
+        direction: Direction,
+    },
+    /// A step leads to ground, or to a pipe that does not connect back
+    Broken { at: (usize, usize) },
 }
 
-pub fn parse_map(input: &str) -> Map {
-    let map_vec: Vec<Vec<Option<Pipe>>> = input
+impl Display for WalkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WalkError::Start { at: (x, y) } => {
+                write!(f, "the start at ({x}, {y}) is not connected to two pipes")
+            }
+            WalkError::LeftMap {
+                from: (x, y),
+                direction,
+            } => write!(
+                f,
+                "the loop leaves the map going {direction} from ({x}, {y})"
+            ),
+            WalkError::Broken { at: (x, y) } => write!(f, "the loop is broken at ({x}, {y})"),
+        }
+    }
+}
+
+/// Parse the maze, one row of tiles per line
+///
+/// # Errors
+///
+/// Returns an error if a tile is not a pipe, ground or the start, if there is no start, or if the
+/// start is not connected to exactly two pipes.
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let map_vec = input
         .lines()
-        .map(|line| line.chars().map(parse_tile).collect())
-        .collect();
-
-    let mut start = (0, 0);
-    'find_start: for (y, row) in map_vec.iter().enumerate() {
-        for (x, tile) in row.iter().enumerate() {
-            if let Some(Pipe::Start) = tile {
-                start = (x, y);
+        .enumerate()
+        .map(|(i, line)| parse_row(line).map_err(|err| err.at_line(i + 1)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The position of the start and its location in the input, for errors
+    let mut start = None;
+    'find_start: for (y, line) in input.lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            if c == 'S' {
+                start = Some(((x, y), Location::of(line, &line[i..=i])));
                 break 'find_start;
             }
         }
     }
 
-    Map {
+    let (start, location) = start.ok_or(ParseError::UnexpectedEnd {
+        expected: "a start tile `S`",
+        line: map_vec.len().max(1),
+    })?;
+
+    let map = Map {
         map: map_vec,
         start,
+    };
+    if map.get_first_directions().len() != 2 {
+        return Err(
+            ParseError::unexpected("a start connected to exactly two pipes", location)
+                .at_line(start.1 + 1),
+        );
     }
+    Ok(map)
 }
 
-fn parse_tile(input: char) -> Option<Pipe> {
-    match input {
-        '-' => Some(Pipe::Horizontal),
-        '|' => Some(Pipe::Vertical),
-        'L' => Some(Pipe::NorthEast),
-        'F' => Some(Pipe::SouthEast),
-        '7' => Some(Pipe::SouthWest),
-        'J' => Some(Pipe::NorthWest),
-        'S' => Some(Pipe::Start),
-        '.' => None,
-        a => panic!("Invalid tile: {a}"),
+fn parse_row(line: &str) -> Result<Vec<Option<Pipe>>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| parse_tile(line, &line[i..i + c.len_utf8()]))
+        .collect()
+}
+
+// Parse a single tile, a one character subslice of the line
+fn parse_tile(line: &str, tile: &str) -> Result<Option<Pipe>, ParseError> {
+    match tile {
+        "-" => Ok(Some(Pipe::Horizontal)),
+        "|" => Ok(Some(Pipe::Vertical)),
+        "L" => Ok(Some(Pipe::NorthEast)),
+        "F" => Ok(Some(Pipe::SouthEast)),
+        "7" => Ok(Some(Pipe::SouthWest)),
+        "J" => Ok(Some(Pipe::NorthWest)),
+        "S" => Ok(Some(Pipe::Start)),
+        "." => Ok(None),
+        _ => Err(ParseError::unexpected(
+            "a pipe, ground or the start",
+            Location::of(line, tile),
+        )),
     }
 }
 
+/// A pipe, named after the directions it connects
 pub enum Pipe {
     Horizontal,
     Vertical,
@@ -131,8 +246,10 @@ impl Display for Pipe {
 }
 
 impl Pipe {
-    // If entering the pipe from the given direction, what direction will you exit?
-    pub fn out_direction(&self, in_direction: &Direction) -> Option<Direction> {
+    /// If entering the pipe from the given direction, what direction will you exit?
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    pub fn out_direction(&self, in_direction: Direction) -> Option<Direction> {
         match (self, in_direction) {
             (Pipe::Horizontal, Direction::East) => Some(Direction::East),
             (Pipe::Horizontal, Direction::West) => Some(Direction::West),
@@ -150,26 +267,29 @@ impl Pipe {
         }
     }
 
-    // Can the pipe connect to the given direction?
-    pub fn can_connect(&self, direction: &Direction) -> bool {
+    /// Can the pipe connect to the given direction?
+    #[must_use]
+    pub fn can_connect(&self, direction: Direction) -> bool {
         matches!(
             (self, direction),
-            (Pipe::Horizontal, Direction::East)
-                | (Pipe::Horizontal, Direction::West)
-                | (Pipe::Vertical, Direction::North)
-                | (Pipe::Vertical, Direction::South)
-                | (Pipe::NorthEast, Direction::North)
-                | (Pipe::NorthEast, Direction::East)
-                | (Pipe::SouthEast, Direction::South)
-                | (Pipe::SouthEast, Direction::East)
-                | (Pipe::SouthWest, Direction::South)
-                | (Pipe::SouthWest, Direction::West)
-                | (Pipe::NorthWest, Direction::North)
-                | (Pipe::NorthWest, Direction::West)
+            (
+                Pipe::Horizontal | Pipe::NorthEast | Pipe::SouthEast,
+                Direction::East
+            ) | (
+                Pipe::Horizontal | Pipe::SouthWest | Pipe::NorthWest,
+                Direction::West
+            ) | (
+                Pipe::Vertical | Pipe::NorthEast | Pipe::NorthWest,
+                Direction::North
+            ) | (
+                Pipe::Vertical | Pipe::SouthEast | Pipe::SouthWest,
+                Direction::South
+            )
         )
     }
 }
 
+/// A compass direction, with north pointing up the map
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     North,
@@ -178,8 +298,23 @@ pub enum Direction {
     West,
 }
 
+// Written in lower case, such as `north`
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::North => "north",
+            Direction::East => "east",
+            Direction::South => "south",
+            Direction::West => "west",
+        };
+        write!(f, "{name}")
+    }
+}
+
 impl Direction {
-    pub fn opposite(&self) -> Self {
+    /// The direction pointing the other way
+    #[must_use]
+    pub fn opposite(self) -> Self {
         match self {
             Direction::North => Direction::South,
             Direction::East => Direction::West,
@@ -188,7 +323,9 @@ impl Direction {
         }
     }
 
-    pub fn offset(&self) -> (isize, isize) {
+    /// The `(x, y)` offset of one step in the direction
+    #[must_use]
+    pub fn offset(self) -> (isize, isize) {
         match self {
             Direction::North => (0, -1),
             Direction::East => (1, 0),
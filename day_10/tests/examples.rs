@@ -0,0 +1,47 @@
+use common::{Solution, SolveError, Unsolved};
+use day_10::Map;
+
+const EXAMPLE_1: &str = include_str!("../src/input_test_1.txt");
+const EXAMPLE_2: &str = include_str!("../src/input_test_2.txt");
+
+#[test]
+fn example_1() {
+    let map = Map::parse(EXAMPLE_1).unwrap();
+    assert_eq!(map.part1(), Ok(4));
+    assert_eq!(map.part2(), Ok(Unsolved));
+}
+
+#[test]
+fn example_2() {
+    let map = Map::parse(EXAMPLE_2).unwrap();
+    assert_eq!(map.part1(), Ok(8));
+    assert_eq!(map.part2(), Ok(Unsolved));
+}
+
+#[test]
+fn start_without_two_pipes() {
+    let err = Map::parse(".S\n..").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 2: expected a start connected to exactly two pipes, found `S`"
+    );
+}
+
+#[test]
+fn broken_loop() {
+    let map = Map::parse("S-7\n|.|\nL-.").unwrap();
+    assert_eq!(
+        map.part1(),
+        Err(SolveError::Unsolvable(
+            "the loop is broken at (2, 2)".to_string()
+        ))
+    );
+
+    let map = Map::parse("S-\n|.").unwrap();
+    assert_eq!(
+        map.part1(),
+        Err(SolveError::Unsolvable(
+            "the loop leaves the map going east from (1, 0)".to_string()
+        ))
+    );
+}
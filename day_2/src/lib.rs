@@ -0,0 +1,658 @@
+//! Day 2: Cube Conundrum
+//!
+//! Find out how many cubes of each color are in the bag from the games played with it.
+
+use common::{parse_number, Location, ParseError, Solution, SolveError};
+use std::fmt;
+use std::str::FromStr;
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The record of all games played, one game per line
+#[derive(Debug)]
+pub struct GameRecord {
+    pub games: Vec<Game>,
+}
+
+/// The colors of the cubes in the puzzle
+pub const RGB: [&str; 3] = ["red", "green", "blue"];
+
+impl Solution for GameRecord {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    // Only the colors of the puzzle are allowed, anything else is likely a typo
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with_colors(input, Some(&RGB))
+    }
+
+    // Sum of the ids of the games possible with the bag of 12 red, 13 green and 14 blue cubes
+    fn part1(&self) -> Result<u64, SolveError> {
+        let bag = ColorCount::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+        Ok(self
+            .games
+            .iter()
+            .filter(|game| game.is_possible(&bag))
+            .map(|game| u64::from(game.id))
+            .sum())
+    }
+
+    // Sum of the power of the minimum set of cubes for each game
+    fn part2(&self) -> Result<u64, SolveError> {
+        self.games.iter().try_fold(0u64, |sum, game| {
+            sum.checked_add(game.power(&RGB)?)
+                .ok_or_else(|| SolveError::Overflow("the sum of the powers".to_string()))
+        })
+    }
+}
+
+impl GameRecord {
+    /// Parse the record, one game per line. If `allowed` is given, only those colors may be
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a valid game or uses a color that is not allowed.
+    pub fn parse_with_colors(input: &str, allowed: Option<&[&str]>) -> Result<Self, ParseError> {
+        let games = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                parse_line_with_colors(line, allowed).map_err(|err| err.at_line(i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { games })
+    }
+
+    /// The game with the given id
+    pub fn game(&self, id: u32) -> Option<&Game> {
+        self.games.iter().find(|game| game.id == id)
+    }
+
+    /// The games that could have been played with the given bag
+    pub fn possible_games<'a>(&'a self, bag: &'a ColorCount) -> impl Iterator<Item = &'a Game> {
+        self.games.iter().filter(|game| game.is_possible(bag))
+    }
+
+    /// The smallest bag making all the games with the given ids possible, `None` if an id is
+    /// not in the record
+    pub fn minimal_bag(&self, ids: &[u32]) -> Option<ColorCount> {
+        let mut bag = ColorCount::default();
+        for id in ids {
+            bag.include(&self.game(*id)?.minimum_bag());
+        }
+        Some(bag)
+    }
+
+    /// The largest total of a bag that makes the game with the given id impossible, taking no
+    /// more cubes of each color than are needed to make every game of the record possible.
+    ///
+    /// `None` if the id is not in the record, or the game revealed no cubes and is possible
+    /// with any bag. An error if the total of that bag overflows a `u64`.
+    pub fn largest_bag_excluding(&self, id: u32) -> Result<Option<u64>, SolveError> {
+        let Some(game) = self.game(id) else {
+            return Ok(None);
+        };
+        let needed = game.minimum_bag();
+        let mut largest = ColorCount::default();
+        for game in &self.games {
+            largest.include(&game.minimum_bag());
+        }
+        let total = largest.total()?;
+
+        // Take away just enough cubes of one color to fall short of the game, picking the color
+        // costing the fewest cubes
+        Ok(needed
+            .iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(color, count)| total - (largest.get(color) - (count - 1)))
+            .max())
+    }
+}
+
+/// Parse a line of the form `Game 1: 3 blue, 4 red; 1 red, 2 green`, with any color names.
+/// Any amount of whitespace is allowed between the parts.
+///
+/// # Errors
+///
+/// Returns an error pointing at the first part of the line not following the grammar.
+pub fn parse_line(line: &str) -> Result<Game, ParseError> {
+    parse_line_with_colors(line, None)
+}
+
+/// Parse a line like [`parse_line`], only allowing the given colors if any are given.
+///
+/// # Errors
+///
+/// Returns an error pointing at the first part of the line not following the grammar, or at
+/// a color that is not allowed.
+pub fn parse_line_with_colors(line: &str, allowed: Option<&[&str]>) -> Result<Game, ParseError> {
+    let mut cursor = Cursor::new(line);
+    cursor.expect("Game", "`Game`")?;
+    let id = cursor.number("a game id")?;
+    cursor.expect(":", "`:`")?;
+
+    let mut draws = vec![parse_draw(&mut cursor, allowed)?];
+    while !cursor.at_end() {
+        cursor.expect(";", "`,`, `;` or the end of the line")?;
+        draws.push(parse_draw(&mut cursor, allowed)?);
+    }
+
+    Ok(Game { id, draws })
+}
+
+/// Parse the cubes revealed in one draw, e.g. ` 3 blue, 4 red`, with any color names.
+///
+/// # Errors
+///
+/// Returns an error pointing at the first part of the draw not following the grammar.
+pub fn parse_variant(variant: &str) -> Result<ColorCount, ParseError> {
+    let mut cursor = Cursor::new(variant);
+    let count = parse_draw(&mut cursor, None)?;
+    if !cursor.at_end() {
+        return Err(cursor.error("`,` or the end of the line"));
+    }
+    Ok(count)
+}
+
+// Parse the comma separated counts of a draw, stopping before anything else
+fn parse_draw(cursor: &mut Cursor, allowed: Option<&[&str]>) -> Result<ColorCount, ParseError> {
+    let mut count = ColorCount::default();
+    loop {
+        cursor.skip_whitespace();
+        let rest = cursor.rest();
+        let cubes = cursor.number("a number of cubes")?;
+        let digits = &rest[..rest.len() - cursor.rest().len()];
+        let color = cursor.word("a color")?;
+        if allowed.is_some_and(|allowed| !allowed.contains(&color)) {
+            return Err(ParseError::unexpected(
+                "one of the allowed colors",
+                Location::of(cursor.line, color),
+            ));
+        }
+        count.add(color, cubes).map_err(|_| {
+            ParseError::unexpected(
+                "a number of cubes keeping the count of the color within u64",
+                Location::of(cursor.line, digits),
+            )
+        })?;
+
+        cursor.skip_whitespace();
+        if !cursor.eat(",") {
+            return Ok(count);
+        }
+    }
+}
+
+// The characters separating the parts of a game besides whitespace
+const SEPARATORS: [char; 3] = [',', ';', ':'];
+
+// A position in the line being parsed
+struct Cursor<'a> {
+    line: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: &'a str) -> Self {
+        Self { line, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    // Move past the literal if the rest starts with it
+    fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.pos += literal.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, literal: &str, expected: &'static str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, expected: &'static str) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+
+        let number = parse_number(self.line, &rest[..len])?;
+        self.pos += len;
+        Ok(number)
+    }
+
+    // A run of characters up to the next whitespace or separator
+    fn word(&mut self, expected: &'static str) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = word_len(rest);
+        if len == 0 {
+            return Err(self.error(expected));
+        }
+
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    // The error for finding something other than what was expected, pointing at the next word
+    // or separator
+    fn error(&self, expected: &'static str) -> ParseError {
+        let rest = self.rest();
+        let len = match word_len(rest) {
+            0 => rest.chars().next().map_or(0, char::len_utf8),
+            len => len,
+        };
+        ParseError::unexpected(expected, Location::of(self.line, &rest[..len]))
+    }
+}
+
+fn word_len(s: &str) -> usize {
+    s.find(|c: char| c.is_whitespace() || SEPARATORS.contains(&c))
+        .unwrap_or(s.len())
+}
+
+/// A game and the cubes revealed in each draw, in the order they were drawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<ColorCount>,
+}
+
+impl Game {
+    /// The smallest bag the game could have been played with, the largest number of cubes of
+    /// each color revealed in any draw
+    pub fn minimum_bag(&self) -> ColorCount {
+        let mut bag = ColorCount::default();
+        for draw in &self.draws {
+            bag.include(draw);
+        }
+        bag
+    }
+
+    /// Whether the game could have been played with the given bag
+    pub fn is_possible(&self, bag: &ColorCount) -> bool {
+        self.draws
+            .iter()
+            .all(|draw| draw.iter().all(|(color, count)| count <= bag.get(color)))
+    }
+
+    /// The power of the game, the product of the cubes of the given colors in the minimum bag.
+    /// A color never revealed makes the power 0. An error if the product overflows a `u64`.
+    pub fn power(&self, colors: &[&str]) -> Result<u64, SolveError> {
+        let bag = self.minimum_bag();
+        colors.iter().try_fold(1u64, |power, color| {
+            power
+                .checked_mul(bag.get(color))
+                .ok_or_else(|| SolveError::Overflow(format!("the power of game {}", self.id)))
+        })
+    }
+}
+
+// The canonical form of a game, e.g. `Game 1: 3 blue, 4 red; 1 red`
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (i, draw) in self.draws.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ";" };
+            write!(f, "{separator} {draw}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of cubes of each color revealed in one draw, or held in a bag. The colors are
+/// kept in the order they were first added.
+#[derive(Debug, Clone, Default)]
+pub struct ColorCount {
+    counts: Vec<(String, u64)>,
+}
+
+impl ColorCount {
+    /// The number of cubes of the color, 0 if it is not in the count
+    pub fn get(&self, color: &str) -> u64 {
+        self.counts
+            .iter()
+            .find(|(name, _)| name == color)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Set the number of cubes of the color
+    pub fn set(&mut self, color: &str, count: u64) {
+        match self.counts.iter_mut().find(|(name, _)| name == color) {
+            Some((_, existing)) => *existing = count,
+            None => self.counts.push((color.to_string(), count)),
+        }
+    }
+
+    /// Add cubes of the color to the count. An error, leaving the count as it was, if the
+    /// number of cubes of the color overflows a `u64`.
+    pub fn add(&mut self, color: &str, count: u64) -> Result<(), SolveError> {
+        let sum = self
+            .get(color)
+            .checked_add(count)
+            .ok_or_else(|| SolveError::Overflow(format!("the number of {color} cubes")))?;
+        self.set(color, sum);
+        Ok(())
+    }
+
+    /// Raise the number of cubes of each color to at least the number in `other`
+    pub fn include(&mut self, other: &ColorCount) {
+        for (color, count) in other.iter() {
+            if count > self.get(color) {
+                self.set(color, count);
+            }
+        }
+    }
+
+    /// The colors and their number of cubes, in the order they were first added
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    /// The total number of cubes. An error if it overflows a `u64`.
+    pub fn total(&self) -> Result<u64, SolveError> {
+        self.counts.iter().try_fold(0u64, |total, (_, count)| {
+            total
+                .checked_add(*count)
+                .ok_or_else(|| SolveError::Overflow("the total number of cubes".to_string()))
+        })
+    }
+}
+
+// The canonical form of a count, e.g. `3 blue, 4 red`
+impl fmt::Display for ColorCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (color, count)) in self.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+// Repeated colors add up, stopping at `u64::MAX` as collecting cannot fail
+impl<'a> FromIterator<(&'a str, u64)> for ColorCount {
+    fn from_iter<I: IntoIterator<Item = (&'a str, u64)>>(iter: I) -> Self {
+        let mut count = ColorCount::default();
+        for (color, cubes) in iter {
+            count.set(color, count.get(color).saturating_add(cubes));
+        }
+        count
+    }
+}
+
+// Counts are equal if they hold the same number of cubes of every color, regardless of order
+impl PartialEq for ColorCount {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().all(|(color, count)| other.get(color) == count)
+            && other.iter().all(|(color, count)| self.get(color) == count)
+    }
+}
+
+impl Eq for ColorCount {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_variant() {
+        let variant = " 2 red";
+        let cc = parse_variant(variant).unwrap();
+
+        assert_eq!(cc.get("red"), 2);
+        assert_eq!(cc.get("green"), 0);
+        assert_eq!(cc.get("blue"), 0);
+
+        let variant = " 2 red, 3 green";
+        let cc = parse_variant(variant).unwrap();
+
+        assert_eq!(cc.get("red"), 2);
+        assert_eq!(cc.get("green"), 3);
+        assert_eq!(cc.get("blue"), 0);
+
+        let variant = " 2 red, 3 green, 4 blue";
+        let cc = parse_variant(variant).unwrap();
+
+        assert_eq!(cc.get("red"), 2);
+        assert_eq!(cc.get("green"), 3);
+        assert_eq!(cc.get("blue"), 4);
+
+        let variant = " 14 red, 3 green, 4 blue";
+        let cc = parse_variant(variant).unwrap();
+
+        assert_eq!(cc.get("red"), 14);
+        assert_eq!(cc.get("green"), 3);
+        assert_eq!(cc.get("blue"), 4);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "Game 1: 2 red, 20 green, 4 blue";
+        let game = parse_line(line).unwrap();
+
+        assert_eq!(game.id, 1);
+        assert_eq!(game.draws.len(), 1);
+        let bag = game.minimum_bag();
+        assert_eq!(bag.get("red"), 2);
+        assert_eq!(bag.get("green"), 20);
+        assert_eq!(bag.get("blue"), 4);
+
+        let line = "Game 2: 2 red, 20 green, 4 blue; 3 red, 5 green, 6 blue";
+        let game = parse_line(line).unwrap();
+
+        assert_eq!(game.id, 2);
+        assert_eq!(
+            game.draws,
+            vec![
+                ColorCount::from_iter([("red", 2), ("green", 20), ("blue", 4)]),
+                ColorCount::from_iter([("red", 3), ("green", 5), ("blue", 6)]),
+            ]
+        );
+        let bag = game.minimum_bag();
+        assert_eq!(bag.get("red"), 3);
+        assert_eq!(bag.get("green"), 20);
+        assert_eq!(bag.get("blue"), 6);
+    }
+
+    #[test]
+    fn test_is_possible_and_power() {
+        let bag = ColorCount::from_iter([("red", 12), ("green", 13), ("blue", 14)]);
+
+        let game = parse_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        assert!(game.is_possible(&bag));
+        assert_eq!(game.power(&RGB), Ok(48));
+
+        let game =
+            parse_line("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+                .unwrap();
+        assert!(!game.is_possible(&bag));
+        assert_eq!(game.power(&RGB), Ok(1560));
+    }
+
+    #[test]
+    fn test_any_colors() {
+        let game = parse_line("Game 7: 2 yellow, 1 purple; 5 yellow, 3 red").unwrap();
+        let bag = game.minimum_bag();
+        assert_eq!(
+            bag,
+            ColorCount::from_iter([("red", 3), ("yellow", 5), ("purple", 1)])
+        );
+        assert_eq!(bag.total(), Ok(9));
+        assert_eq!(game.power(&["yellow", "purple"]), Ok(5));
+        assert_eq!(game.power(&RGB), Ok(0));
+        assert!(game.is_possible(&bag));
+        assert!(!game.is_possible(&ColorCount::from_iter([("yellow", 9), ("red", 9)])));
+
+        let allowed = ["yellow", "purple", "red"];
+        assert!(parse_line_with_colors(
+            "Game 7: 2 yellow, 1 purple; 5 yellow, 3 red",
+            Some(&allowed)
+        )
+        .is_ok());
+
+        // Repeated colors in a draw add up
+        let cc = parse_variant(" 2 red, 3 red").unwrap();
+        assert_eq!(cc.get("red"), 5);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let record = GameRecord::parse("Game 1: 70000 red, 70000 green, 1 blue").unwrap();
+        assert_eq!(record.part2(), Ok(4_900_000_000));
+
+        let game = parse_line("Game 1: 4294967296 red, 4294967296 green, 1 blue").unwrap();
+        assert_eq!(
+            game.power(&RGB),
+            Err(SolveError::Overflow("the power of game 1".to_string()))
+        );
+
+        let bag = parse_variant("18446744073709551615 red, 1 green").unwrap();
+        assert_eq!(
+            bag.total(),
+            Err(SolveError::Overflow(
+                "the total number of cubes".to_string()
+            ))
+        );
+
+        let mut bag = parse_variant("18446744073709551615 red").unwrap();
+        assert!(bag.add("red", 1).is_err());
+        assert_eq!(bag.get("red"), u64::MAX);
+    }
+
+    #[test]
+    fn test_queries() {
+        let record = GameRecord::parse(include_str!("input_test.txt")).unwrap();
+
+        let bag = parse_variant("12 red, 13 green, 14 blue").unwrap();
+        let ids: Vec<u32> = record.possible_games(&bag).map(|game| game.id).collect();
+        assert_eq!(ids, vec![1, 2, 5]);
+
+        assert_eq!(
+            record.minimal_bag(&[1, 2]),
+            Some(ColorCount::from_iter([
+                ("red", 4),
+                ("green", 3),
+                ("blue", 6)
+            ]))
+        );
+        assert_eq!(record.minimal_bag(&[]), Some(ColorCount::default()));
+        assert_eq!(record.minimal_bag(&[1, 9]), None);
+
+        // The bag for all games holds 20 red, 13 green and 15 blue. Game 1 needs 6 blue, so
+        // keeping only 5 blue excludes it at the smallest cost.
+        assert_eq!(record.largest_bag_excluding(1), Ok(Some(38)));
+        assert_eq!(record.largest_bag_excluding(9), Ok(None));
+    }
+
+    #[test]
+    fn test_flexible_whitespace() {
+        let game = parse_line("  Game   12 :3 blue,4  red ;\t1 red  ").unwrap();
+        assert_eq!(game.id, 12);
+        assert_eq!(
+            game.draws,
+            vec![
+                ColorCount::from_iter([("blue", 3), ("red", 4)]),
+                ColorCount::from_iter([("red", 1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let lines = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue",
+            "Game 100: 7 yellow, 0 purple; 1 red",
+        ];
+        for line in lines {
+            let game = parse_line(line).unwrap();
+            assert_eq!(game.to_string(), line);
+            assert_eq!(parse_line(&game.to_string()).unwrap(), game);
+        }
+
+        let game = parse_line("Game 3 :3 blue,4 red;1 red").unwrap();
+        assert_eq!(game.to_string(), "Game 3: 3 blue, 4 red; 1 red");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_line_with_colors("Game 1: 2 red, 3 purple", Some(&RGB)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 18: expected one of the allowed colors, found `purple`"
+        );
+
+        let err = parse_line("Game 1: 2 red; x green").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 16: expected a number of cubes, found `x`"
+        );
+
+        let err = GameRecord::parse("Game 1: 2 red\nGame two: 2 red").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a game id, found `two`"
+        );
+
+        let cases = [
+            (
+                "Gaem 1: 2 red",
+                "line 1, column 1: expected `Game`, found `Gaem`",
+            ),
+            ("Game 1 2 red", "line 1, column 8: expected `:`, found `2`"),
+            (
+                "Game 1: 2 red 3 blue",
+                "line 1, column 15: expected `,`, `;` or the end of the line, found `3`",
+            ),
+            (
+                "Game 1: 2 red,",
+                "line 1, column 15: expected a number of cubes, found the end of the line",
+            ),
+            (
+                "Game 1: 2 ; 3 red",
+                "line 1, column 11: expected a color, found `;`",
+            ),
+            (
+                "Game 1: 99999999999999999999 red",
+                "line 1, column 9: invalid number `99999999999999999999`",
+            ),
+            (
+                "Game 1: 18446744073709551615 red, 1 red",
+                "line 1, column 35: expected a number of cubes keeping the count of the color \
+                 within u64, found `1`",
+            ),
+        ];
+        for (line, message) in cases {
+            assert_eq!(parse_line(line).unwrap_err().to_string(), message);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use common::Solution;
+use day_2::GameRecord;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let record = GameRecord::parse(EXAMPLE).unwrap();
+    assert_eq!(record.part1(), Ok(8));
+    assert_eq!(record.part2(), Ok(2286));
+}
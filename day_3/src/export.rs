@@ -0,0 +1,358 @@
+use crate::schematic::{Point, Rendering, Schematic, Span};
+use std::fmt::{self, Write};
+
+// Width and height of a cell in an SVG, in pixels
+const CELL_WIDTH: usize = 10;
+const CELL_HEIGHT: usize = 18;
+const FONT_SIZE: usize = 16;
+const MARGIN: usize = 10;
+
+// The class, colour and legend text of each kind of annotated cell
+const STYLES: [(&str, &str, &str); 4] = [
+    ("counted", "#2e9e44", "part number"),
+    ("uncounted", "#2f6fd0", "number adjacent to no symbol"),
+    ("gear", "#c99a00", "gear"),
+    ("symbol", "#d03030", "symbol"),
+];
+const BLANK_COLOUR: &str = "#888888";
+
+// A piece of a row, numbers taken as a whole
+enum Item<'a> {
+    Number {
+        span: &'a Span,
+        text: String,
+        counted: bool,
+    },
+    Symbol {
+        point: Point,
+        c: char,
+        gear: bool,
+    },
+    Blank {
+        x: usize,
+        text: String,
+    },
+}
+
+impl Item<'_> {
+    fn column(&self) -> usize {
+        match self {
+            Item::Number { span, .. } => span.start,
+            Item::Symbol { point, .. } => point.x,
+            Item::Blank { x, .. } => *x,
+        }
+    }
+
+    fn class(&self) -> Option<&'static str> {
+        match self {
+            Item::Number { counted: true, .. } => Some("counted"),
+            Item::Number { counted: false, .. } => Some("uncounted"),
+            Item::Symbol { gear: true, .. } => Some("gear"),
+            Item::Symbol { gear: false, .. } => Some("symbol"),
+            Item::Blank { .. } => None,
+        }
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Item::Number { text, .. } | Item::Blank { text, .. } => text.clone(),
+            Item::Symbol { c, .. } => c.to_string(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Item::Number { span, .. } => span.end - span.start,
+            Item::Symbol { .. } => 1,
+            Item::Blank { text, .. } => text.chars().count(),
+        }
+    }
+
+    // What hovering over the item shows
+    fn tooltip(&self, schematic: &Schematic) -> Option<String> {
+        match self {
+            Item::Number { span, .. } => {
+                let symbols: Vec<String> = schematic
+                    .adjacent_symbols(span)
+                    .iter()
+                    .map(|p| format!("{} at {p}", schematic.get(p).unwrap()))
+                    .collect();
+                let symbols = if symbols.is_empty() {
+                    "no symbol".to_string()
+                } else {
+                    symbols.join(", ")
+                };
+                Some(format!(
+                    "{} at {}, adjacent to {symbols}",
+                    span.value,
+                    span.beginning()
+                ))
+            }
+            Item::Symbol { point, c, gear } => {
+                let values: Vec<String> = schematic
+                    .adjacent_spans(point)
+                    .iter()
+                    .map(|span| span.value.to_string())
+                    .collect();
+                if *gear {
+                    match schematic.gear_ratio(point) {
+                        Ok(Some(ratio)) => Some(format!("gear {c} at {point}, ratio {ratio}")),
+                        _ => Some(format!("gear {c} at {point}, ratio too large for a u64")),
+                    }
+                } else if values.is_empty() {
+                    Some(format!("{c} at {point}, adjacent to no number"))
+                } else {
+                    Some(format!("{c} at {point}, adjacent to {}", values.join(", ")))
+                }
+            }
+            Item::Blank { .. } => None,
+        }
+    }
+}
+
+// Escape text for use in HTML and SVG, both as content and in attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Rendering<'_> {
+    // The items of each row, from left to right
+    fn items(&self) -> Vec<Vec<Item<'_>>> {
+        let schematic = self.schematic();
+        schematic
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                let mut items = Vec::new();
+                let mut x = 0;
+                while x < row.len() {
+                    let point = Point { x, y };
+                    if let Some(span) = schematic.span_at(&point) {
+                        items.push(Item::Number {
+                            span,
+                            text: row[span.start..span.end].iter().collect(),
+                            counted: self.parts().contains(span),
+                        });
+                        x = span.end;
+                    } else if schematic.is_symbol(&point) {
+                        items.push(Item::Symbol {
+                            gear: schematic.is_gear(&point),
+                            point,
+                            c: row[x],
+                        });
+                        x += 1;
+                    } else {
+                        match items.last_mut() {
+                            Some(Item::Blank { text, .. }) => text.push(row[x]),
+                            _ => items.push(Item::Blank {
+                                x,
+                                text: row[x].to_string(),
+                            }),
+                        }
+                        x += 1;
+                    }
+                }
+                items
+            })
+            .collect()
+    }
+
+    /// A standalone HTML page of the schematic, colour-coded like the terminal rendering, with a
+    /// legend and a tooltip on every number and symbol
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html)
+            .expect("writing to a String cannot fail");
+        html
+    }
+
+    fn write_html(&self, out: &mut impl Write) -> fmt::Result {
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html>")?;
+        writeln!(out, "<head>")?;
+        writeln!(out, "<meta charset=\"utf-8\">")?;
+        writeln!(out, "<title>Engine schematic</title>")?;
+        writeln!(out, "<style>")?;
+        writeln!(out, "body {{ font-family: sans-serif; }}")?;
+        writeln!(out, "pre {{ color: {BLANK_COLOUR}; line-height: 1.2; }}")?;
+        writeln!(out, ".legend {{ list-style: none; padding: 0; }}")?;
+        for (class, colour, _) in STYLES {
+            writeln!(out, ".{class} {{ color: {colour}; font-weight: bold; }}")?;
+        }
+        writeln!(out, "</style>")?;
+        writeln!(out, "</head>")?;
+        writeln!(out, "<body>")?;
+        writeln!(out, "<ul class=\"legend\">")?;
+        for (class, _, description) in STYLES {
+            writeln!(
+                out,
+                "<li><span class=\"{class}\">&#9632;</span> {description}</li>"
+            )?;
+        }
+        writeln!(out, "</ul>")?;
+        write!(out, "<pre>")?;
+        for row in self.items() {
+            for item in row {
+                let text = escape(&item.text());
+                match (item.class(), item.tooltip(self.schematic())) {
+                    (Some(class), Some(tooltip)) => write!(
+                        out,
+                        "<span class=\"{class}\" title=\"{}\">{text}</span>",
+                        escape(&tooltip)
+                    )?,
+                    _ => write!(out, "{text}")?,
+                }
+            }
+            writeln!(out)?;
+        }
+        writeln!(out, "</pre>")?;
+        writeln!(out, "</body>")?;
+        writeln!(out, "</html>")
+    }
+
+    /// A standalone SVG image of the schematic, colour-coded like the terminal rendering, with a
+    /// legend below it and a tooltip on every number and symbol
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        self.write_svg(&mut svg)
+            .expect("writing to a String cannot fail");
+        svg
+    }
+
+    fn write_svg(&self, out: &mut impl Write) -> fmt::Result {
+        let items = self.items();
+        let columns = self
+            .schematic()
+            .rows()
+            .map(<[char]>::len)
+            .max()
+            .unwrap_or(0);
+        let legend_top = MARGIN + (items.len() + 1) * CELL_HEIGHT;
+        let width = 2 * MARGIN + columns.max(30) * CELL_WIDTH;
+        let height = legend_top + STYLES.len() * CELL_HEIGHT + MARGIN;
+
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">"
+        )?;
+        writeln!(out, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>")?;
+        writeln!(
+            out,
+            "<g font-family=\"monospace\" font-size=\"{FONT_SIZE}\" fill=\"{BLANK_COLOUR}\">"
+        )?;
+        for (y, row) in items.iter().enumerate() {
+            let baseline = MARGIN + (y + 1) * CELL_HEIGHT;
+            for item in row {
+                let x = MARGIN + item.column() * CELL_WIDTH;
+                let length = item.len() * CELL_WIDTH;
+                let text = escape(&item.text());
+                let position = format!(
+                    "x=\"{x}\" y=\"{baseline}\" textLength=\"{length}\" \
+                     lengthAdjust=\"spacingAndGlyphs\""
+                );
+                match (item.class(), item.tooltip(self.schematic())) {
+                    (Some(class), Some(tooltip)) => {
+                        let colour = STYLES
+                            .iter()
+                            .find(|(name, _, _)| *name == class)
+                            .map_or(BLANK_COLOUR, |(_, colour, _)| colour);
+                        writeln!(
+                            out,
+                            "<text {position} class=\"{class}\" fill=\"{colour}\" \
+                             font-weight=\"bold\"><title>{}</title>{text}</text>",
+                            escape(&tooltip)
+                        )?;
+                    }
+                    _ => writeln!(out, "<text {position} xml:space=\"preserve\">{text}</text>")?,
+                }
+            }
+        }
+        writeln!(out, "</g>")?;
+
+        writeln!(
+            out,
+            "<g font-family=\"sans-serif\" font-size=\"{FONT_SIZE}\">"
+        )?;
+        for (i, (_, colour, description)) in STYLES.iter().enumerate() {
+            let baseline = legend_top + (i + 1) * CELL_HEIGHT;
+            let top = baseline - CELL_HEIGHT / 2 - 4;
+            writeln!(
+                out,
+                "<rect x=\"{MARGIN}\" y=\"{top}\" width=\"10\" height=\"10\" fill=\"{colour}\"/>"
+            )?;
+            writeln!(
+                out,
+                "<text x=\"{}\" y=\"{baseline}\">{description}</text>",
+                MARGIN + 2 * CELL_WIDTH
+            )?;
+        }
+        writeln!(out, "</g>")?;
+        writeln!(out, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "467..114..\n...*......\n..35<&633.";
+
+    #[test]
+    fn test_html() {
+        let schematic: Schematic = INPUT.into();
+        let parts = schematic.part_numbers();
+        let html = schematic.render(&parts).to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<span class=\"counted\">&#9632;</span> part number"));
+        assert!(html.contains(
+            "<span class=\"counted\" title=\"467 at (0, 0), adjacent to * at (3, 1)\">467</span>"
+        ));
+        assert!(html.contains(
+            "<span class=\"uncounted\" title=\"114 at (5, 0), adjacent to no symbol\">114</span>"
+        ));
+        assert!(html.contains(
+            "<span class=\"symbol\" title=\"&lt; at (4, 2), adjacent to 35\">&lt;</span>"
+        ));
+        assert!(html.contains("title=\"gear * at (3, 1), ratio 16345\">*</span>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_svg() {
+        let schematic: Schematic = INPUT.into();
+        let parts = schematic.part_numbers();
+        let svg = schematic.render(&parts).to_svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("<title>633 at (6, 2), adjacent to &amp; at (5, 2)</title>633</text>"));
+        assert!(svg.contains(">number adjacent to no symbol</text>"));
+        assert_eq!(svg.matches("<text").count(), svg.matches("</text>").count());
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_gear_ratio_overflow() {
+        let schematic: Schematic = "4294967296*4294967296".into();
+        let parts = schematic.part_numbers();
+        let html = schematic.render(&parts).to_html();
+        assert!(html.contains("title=\"gear * at (10, 0), ratio too large for a u64\">*</span>"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a<b>&\"'"), "a&lt;b&gt;&amp;&quot;&#39;");
+    }
+}
@@ -0,0 +1,15 @@
+//! Day 3: Gear Ratios
+//!
+//! Find the part numbers and gears in the engine schematic.
+
+pub mod export;
+pub mod report;
+pub mod rules;
+pub mod schematic;
+
+pub use report::{NumberEntry, Report, SymbolEntry};
+pub use rules::{Connectivity, Rules};
+pub use schematic::{PartNumbers, Point, Rendering, Schematic, Span};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
@@ -0,0 +1,233 @@
+use crate::schematic::{Point, Schematic};
+use serde::Serialize;
+
+/// A number on the schematic, located by its first digit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NumberEntry {
+    pub at: Point,
+    pub value: u64,
+}
+
+/// A symbol on the schematic with the numbers adjacent to it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolEntry {
+    pub symbol: char,
+    pub at: Point,
+    pub numbers: Vec<NumberEntry>,
+    /// The product of the adjacent numbers, if the symbol is a gear and the product fits in a
+    /// u64
+    pub gear_ratio: Option<u64>,
+    /// Whether the symbol is a gear whose ratio is too large for a u64
+    pub gear_ratio_overflow: bool,
+}
+
+/// Every symbol of a schematic with the numbers it makes count, and the numbers that are
+/// adjacent to no symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub symbols: Vec<SymbolEntry>,
+    pub lone_numbers: Vec<NumberEntry>,
+}
+
+// One line of the CSV export. Symbols get a line per adjacent number, or a single line without
+// a number, and lone numbers get a line without a symbol.
+#[derive(Serialize)]
+struct CsvRecord {
+    symbol: Option<char>,
+    symbol_x: Option<usize>,
+    symbol_y: Option<usize>,
+    number: Option<u64>,
+    number_x: Option<usize>,
+    number_y: Option<usize>,
+    gear_ratio: Option<u64>,
+    // Only filled in, with `true`, for gears whose ratio overflows
+    gear_ratio_overflow: Option<bool>,
+}
+
+impl CsvRecord {
+    fn new(symbol: Option<&SymbolEntry>, number: Option<&NumberEntry>) -> Self {
+        CsvRecord {
+            symbol: symbol.map(|entry| entry.symbol),
+            symbol_x: symbol.map(|entry| entry.at.x),
+            symbol_y: symbol.map(|entry| entry.at.y),
+            number: number.map(|entry| entry.value),
+            number_x: number.map(|entry| entry.at.x),
+            number_y: number.map(|entry| entry.at.y),
+            gear_ratio: symbol.and_then(|entry| entry.gear_ratio),
+            gear_ratio_overflow: symbol
+                .is_some_and(|entry| entry.gear_ratio_overflow)
+                .then_some(true),
+        }
+    }
+}
+
+impl Schematic {
+    /// List every symbol with its adjacent numbers and gear ratio, from top to bottom and left to
+    /// right, followed by the numbers adjacent to no symbol. A gear ratio too large for a u64 is
+    /// flagged rather than given.
+    pub fn report(&self) -> Report {
+        let symbols = self
+            .symbol_points()
+            .iter()
+            .map(|p| {
+                let numbers: Vec<NumberEntry> = self
+                    .get_adjacent_numbers(p)
+                    .into_iter()
+                    .map(|at| NumberEntry {
+                        value: self.get_number(&at),
+                        at,
+                    })
+                    .collect();
+                let gear_ratio = self.gear_ratio(p);
+                SymbolEntry {
+                    symbol: *self.get(p).unwrap(),
+                    at: p.clone(),
+                    numbers,
+                    gear_ratio_overflow: gear_ratio.is_err(),
+                    gear_ratio: gear_ratio.unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let parts = self.part_numbers();
+        let lone_numbers = self
+            .spans()
+            .iter()
+            .filter(|span| !parts.contains(span))
+            .map(|span| NumberEntry {
+                at: span.beginning(),
+                value: span.value,
+            })
+            .collect();
+
+        Report {
+            symbols,
+            lone_numbers,
+        }
+    }
+}
+
+impl Report {
+    /// The report as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("the report is always valid JSON")
+    }
+
+    /// The report as CSV with a header, one line per symbol and adjacent number, and one line
+    /// per lone number. Fields that do not apply are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        let mut records = Vec::new();
+        for symbol in &self.symbols {
+            if symbol.numbers.is_empty() {
+                records.push(CsvRecord::new(Some(symbol), None));
+            }
+            for number in &symbol.numbers {
+                records.push(CsvRecord::new(Some(symbol), Some(number)));
+            }
+        }
+        for number in &self.lone_numbers {
+            records.push(CsvRecord::new(None, Some(number)));
+        }
+        for record in records {
+            writer
+                .serialize(record)
+                .expect("writing to memory cannot fail");
+        }
+        let bytes = writer.into_inner().expect("writing to memory cannot fail");
+        String::from_utf8(bytes).expect("the report is always valid UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "467..114..\n...*......\n..35..633.\n,.....#...";
+
+    #[test]
+    fn test_report() {
+        let schematic: Schematic = INPUT.into();
+        let report = schematic.report();
+
+        assert_eq!(report.symbols.len(), 3);
+        assert_eq!(
+            report.symbols[0],
+            SymbolEntry {
+                symbol: '*',
+                at: Point { x: 3, y: 1 },
+                numbers: vec![
+                    NumberEntry {
+                        at: Point { x: 0, y: 0 },
+                        value: 467
+                    },
+                    NumberEntry {
+                        at: Point { x: 2, y: 2 },
+                        value: 35
+                    },
+                ],
+                gear_ratio: Some(16345),
+                gear_ratio_overflow: false,
+            }
+        );
+        assert_eq!(report.symbols[1].symbol, ',');
+        assert!(report.symbols[1].numbers.is_empty());
+        assert_eq!(report.symbols[2].numbers[0].value, 633);
+        assert_eq!(report.symbols[2].gear_ratio, None);
+        assert_eq!(
+            report.lone_numbers,
+            vec![NumberEntry {
+                at: Point { x: 5, y: 0 },
+                value: 114
+            }]
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let schematic: Schematic = INPUT.into();
+        let csv = schematic.report().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "symbol,symbol_x,symbol_y,number,number_x,number_y,gear_ratio,gear_ratio_overflow",
+                "*,3,1,467,0,0,16345,",
+                "*,3,1,35,2,2,16345,",
+                "\",\",0,3,,,,,",
+                "#,6,3,633,6,2,,",
+                ",,,114,5,0,,",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let schematic: Schematic = "1.\n.#".into();
+        let json: serde_json::Value = serde_json::from_str(&schematic.report().to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "symbols": [{
+                    "symbol": "#",
+                    "at": { "x": 1, "y": 1 },
+                    "numbers": [{ "at": { "x": 0, "y": 0 }, "value": 1 }],
+                    "gear_ratio": null,
+                    "gear_ratio_overflow": false,
+                }],
+                "lone_numbers": [],
+            })
+        );
+    }
+
+    #[test]
+    fn test_gear_ratio_overflow() {
+        let schematic: Schematic = "4294967296*4294967296".into();
+        let report = schematic.report();
+        assert_eq!(report.symbols[0].gear_ratio, None);
+        assert!(report.symbols[0].gear_ratio_overflow);
+
+        let csv = report.to_csv();
+        assert_eq!(csv.lines().nth(1), Some("*,10,0,4294967296,0,0,,true"));
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+/// Which cells around a point count as adjacent to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Only the cells above, below, left and right
+    Four,
+    /// The cells around the point, diagonals included
+    #[default]
+    Eight,
+}
+
+impl Connectivity {
+    /// The offsets `(dx, dy)` from a point to its neighbours, column by column from the left
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// What counts as a symbol, a gear and a neighbour on a schematic. The default rules are the
+/// ones of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// The characters that are part symbols. `None` makes every character that is not a digit,
+    /// whitespace or the blank character a symbol.
+    pub symbols: Option<HashSet<char>>,
+    /// The symbol that can be a gear
+    pub gear: char,
+    /// How many numbers a gear has to be adjacent to
+    pub gear_neighbours: usize,
+    /// Which cells are adjacent to each other
+    pub connectivity: Connectivity,
+    /// The character of empty cells
+    pub blank: char,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            symbols: None,
+            gear: '*',
+            gear_neighbours: 2,
+            connectivity: Connectivity::Eight,
+            blank: '.',
+        }
+    }
+}
+
+impl Rules {
+    /// Whether the character is a part symbol under these rules
+    pub fn is_symbol(&self, c: char) -> bool {
+        if c.is_ascii_digit() || c.is_whitespace() || c == self.blank {
+            return false;
+        }
+        match &self.symbols {
+            Some(symbols) => symbols.contains(&c),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_symbol() {
+        let rules = Rules::default();
+        assert!(rules.is_symbol('*'));
+        assert!(rules.is_symbol('#'));
+        assert!(!rules.is_symbol('.'));
+        assert!(!rules.is_symbol('7'));
+        assert!(!rules.is_symbol(' '));
+        assert!(rules.is_symbol('½'));
+        assert!(rules.is_symbol('٣'));
+
+        let rules = Rules {
+            symbols: Some(HashSet::from(['*', '.'])),
+            blank: '_',
+            ..Rules::default()
+        };
+        assert!(rules.is_symbol('*'));
+        assert!(rules.is_symbol('.'));
+        assert!(!rules.is_symbol('#'));
+        assert!(!rules.is_symbol('_'));
+    }
+}
@@ -0,0 +1,779 @@
+use crate::rules::Rules;
+use colored::Colorize;
+use common::{Location, ParseError, Solution, SolveError};
+use serde::Serialize;
+use std::{collections::HashSet, hash::Hash};
+
+impl Solution for Schematic {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Schematic::parse_with_rules(input, Rules::default())
+    }
+
+    // Sum of all numbers adjacent to a symbol
+    fn part1(&self) -> Result<u64, SolveError> {
+        self.calculate_partnumbers()
+    }
+
+    // Sum of the gear ratios of all gears
+    fn part2(&self) -> Result<u64, SolveError> {
+        self.calculate_gear_rations()
+    }
+}
+
+// Panics if a number on the schematic does not fit in a u64, use `Schematic::parse` to get an
+// error instead
+impl From<&str> for Schematic {
+    fn from(input: &str) -> Self {
+        Schematic::parse(input).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+// Every number on the schematic, found in one pass over the input
+struct Index {
+    spans: Vec<Span>,
+    cells: Vec<Vec<Option<usize>>>,
+}
+
+// Find the numbers of the schematic, runs of ASCII digits, failing on numbers that do not fit
+// in a u64. Rows may have
+// different lengths, and `lines` drops the `\r` of CRLF line endings.
+fn index(input: &str) -> Result<Index, ParseError> {
+    let mut spans = Vec::new();
+    let mut cells = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        let mut row = Vec::with_capacity(chars.len());
+        let mut x = 0;
+        while x < chars.len() {
+            let (offset, c) = chars[x];
+            if !c.is_ascii_digit() {
+                row.push(None);
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+            while x < chars.len() && chars[x].1.is_ascii_digit() {
+                x += 1;
+            }
+            let end_offset = chars.get(x).map_or(line.len(), |&(offset, _)| offset);
+            // A run of ASCII digits only fails to parse when it is too wide for a u64
+            let digits = &line[offset..end_offset];
+            let value = digits.parse().map_err(|_| {
+                ParseError::unexpected("a number that fits in a u64", Location::of(line, digits))
+                    .at_line(y + 1)
+            })?;
+            row.extend(std::iter::repeat_n(Some(spans.len()), x - start));
+            spans.push(Span {
+                row: y,
+                start,
+                end: x,
+                value,
+            });
+        }
+        cells.push(row);
+    }
+    Ok(Index { spans, cells })
+}
+
+// The positions of the characters that are symbols under the rules
+fn find_symbols(array: &[Vec<char>], rules: &Rules) -> Vec<Point> {
+    let mut symbol_points = Vec::new();
+    for (y, row) in array.iter().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            if rules.is_symbol(*c) {
+                symbol_points.push(Point { x, y });
+            }
+        }
+    }
+    symbol_points
+}
+
+fn create_2d_array(input: &str) -> Vec<Vec<char>> {
+    let mut array = Vec::new();
+    for line in input.lines() {
+        let mut row = Vec::new();
+        for c in line.chars() {
+            row.push(c)
+        }
+        array.push(row);
+    }
+    array
+}
+
+/// The engine schematic as a grid of characters, with its numbers and symbols indexed when it is
+/// read
+#[derive(Clone)]
+pub struct Schematic {
+    array: Vec<Vec<char>>,
+    rules: Rules,
+    spans: Vec<Span>,
+    // The index into `spans` of the number covering each cell, if any
+    cells: Vec<Vec<Option<usize>>>,
+    symbol_points: Vec<Point>,
+}
+
+/// A position on the schematic, with `y` counting rows from the top
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+// Written as `(x, y)`
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Hash for Point {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
+/// The numbers of a schematic that are adjacent to a symbol, as found by
+/// [`Schematic::part_numbers`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartNumbers {
+    spans: Vec<Span>,
+    beginnings: HashSet<Point>,
+}
+
+impl PartNumbers {
+    /// The counted numbers, from top to bottom and left to right
+    pub fn iter(&self) -> impl Iterator<Item = &Span> {
+        self.spans.iter()
+    }
+
+    /// Whether the given number was counted
+    pub fn contains(&self, span: &Span) -> bool {
+        self.beginnings.contains(&span.beginning())
+    }
+
+    /// The number of counted numbers
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Whether no number was counted
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Sum of the counted numbers
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if the sum does not fit in a u64.
+    pub fn sum(&self) -> Result<u64, SolveError> {
+        self.spans
+            .iter()
+            .try_fold(0u64, |sum, span| sum.checked_add(span.value))
+            .ok_or_else(|| SolveError::Overflow("the sum of the part numbers".to_string()))
+    }
+}
+
+/// A number on the schematic, covering the columns `start..end` of its row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u64,
+}
+
+impl Span {
+    /// The first digit of the number
+    pub fn beginning(&self) -> Point {
+        Point {
+            x: self.start,
+            y: self.row,
+        }
+    }
+}
+
+impl Schematic {
+    /// Read a schematic where symbols, gears and adjacency follow the given rules
+    pub fn parse_with_rules(input: &str, rules: Rules) -> Result<Self, ParseError> {
+        let Index { spans, cells } = index(input)?;
+        let array = create_2d_array(input);
+        let symbol_points = find_symbols(&array, &rules);
+        Ok(Schematic {
+            array,
+            rules,
+            spans,
+            cells,
+            symbol_points,
+        })
+    }
+
+    /// The same schematic evaluated under other rules
+    pub fn with_rules(&self, rules: Rules) -> Schematic {
+        Schematic {
+            symbol_points: find_symbols(&self.array, &rules),
+            rules,
+            ..self.clone()
+        }
+    }
+
+    /// The rules the schematic is evaluated under
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// The character at the given point, `None` if it is outside the schematic
+    pub fn get(&self, p: &Point) -> Option<&char> {
+        self.array.get(p.y).and_then(|row| row.get(p.x))
+    }
+
+    /// The rows of characters, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.array.iter().map(Vec::as_slice)
+    }
+
+    /// All numbers on the schematic, from top to bottom and left to right
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// The number covering the given point, `None` if there is no digit there
+    pub fn span_at(&self, p: &Point) -> Option<&Span> {
+        let i = (*self.cells.get(p.y)?.get(p.x)?)?;
+        Some(&self.spans[i])
+    }
+
+    /// The positions of all part symbols, from top to bottom and left to right
+    pub fn symbol_points(&self) -> &[Point] {
+        &self.symbol_points
+    }
+
+    // Indices into `spans` of the numbers adjacent to the given point, each listed once
+    fn adjacent_span_ids(&self, p: &Point) -> Vec<usize> {
+        let mut ids = Vec::new();
+        for &(dx, dy) in self.rules.connectivity.offsets() {
+            let (Some(x), Some(y)) = (p.x.checked_add_signed(dx), p.y.checked_add_signed(dy))
+            else {
+                continue;
+            };
+            let id = self
+                .cells
+                .get(y)
+                .and_then(|row| row.get(x))
+                .copied()
+                .flatten();
+            if let Some(id) = id {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids
+    }
+
+    /// The numbers adjacent to the given point
+    pub fn adjacent_spans(&self, p: &Point) -> Vec<&Span> {
+        self.adjacent_span_ids(p)
+            .into_iter()
+            .map(|i| &self.spans[i])
+            .collect()
+    }
+
+    /// The symbols adjacent to any digit of the given number, from top to bottom and left to
+    /// right
+    pub fn adjacent_symbols(&self, span: &Span) -> Vec<Point> {
+        let mut symbols = Vec::new();
+        for x in span.start..span.end {
+            for &(dx, dy) in self.rules.connectivity.offsets() {
+                let (Some(x), Some(y)) =
+                    (x.checked_add_signed(dx), span.row.checked_add_signed(dy))
+                else {
+                    continue;
+                };
+                let p = Point { x, y };
+                if self.is_symbol(&p) && !symbols.contains(&p) {
+                    symbols.push(p);
+                }
+            }
+        }
+        symbols.sort_by_key(|p| (p.y, p.x));
+        symbols
+    }
+
+    /// Returns a list of the beginnings of all adjacent numbers to the given point
+    pub fn get_adjacent_numbers(&self, p: &Point) -> Vec<Point> {
+        self.adjacent_spans(p)
+            .into_iter()
+            .map(Span::beginning)
+            .collect()
+    }
+
+    /// The first digit of the number containing the given point
+    pub fn get_beginning_of_number(&self, p: &Point) -> Point {
+        if let Some(span) = self.span_at(p) {
+            return span.beginning();
+        }
+
+        // Not on a number, walk from the point like before the numbers were indexed
+        let mut x = p.x;
+        while let Some(c) = self.get(&Point { x, y: p.y }) {
+            if !c.is_ascii_digit() {
+                x += 1;
+                break;
+            }
+            if x == 0 {
+                break;
+            }
+            x -= 1;
+        }
+        Point { x, y: p.y }
+    }
+
+    /// Whether the given point holds a part symbol
+    pub fn is_symbol(&self, p: &Point) -> bool {
+        match self.get(p) {
+            Some(c) => self.rules.is_symbol(*c),
+            None => false,
+        }
+    }
+
+    /// The number starting at the given point. Starting inside a number reads its remaining
+    /// digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no number at the point.
+    pub fn get_number(&self, p: &Point) -> u64 {
+        let span = self.span_at(p).unwrap();
+        let digits = (span.end - p.x) as u32;
+        10u64
+            .checked_pow(digits)
+            .map_or(span.value, |modulus| span.value % modulus)
+    }
+
+    /// The numbers adjacent to at least one symbol. Each number is counted once, however many
+    /// symbols it touches.
+    pub fn part_numbers(&self) -> PartNumbers {
+        let mut counted = vec![false; self.spans.len()];
+        for p in &self.symbol_points {
+            for i in self.adjacent_span_ids(p) {
+                counted[i] = true;
+            }
+        }
+
+        let spans: Vec<Span> = self
+            .spans
+            .iter()
+            .zip(counted)
+            .filter(|(_, counted)| *counted)
+            .map(|(span, _)| span.clone())
+            .collect();
+        let beginnings = spans.iter().map(Span::beginning).collect();
+        PartNumbers { spans, beginnings }
+    }
+
+    /// Sum of all numbers adjacent to a symbol
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if the sum does not fit in a u64.
+    pub fn calculate_partnumbers(&self) -> Result<u64, SolveError> {
+        self.part_numbers().sum()
+    }
+
+    /// Whether the given point is a gear symbol adjacent to exactly as many numbers as the rules
+    /// require, by default a `*` next to two numbers
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is outside the schematic.
+    pub fn is_gear(&self, p: &Point) -> bool {
+        let c = self.get(p).unwrap();
+        *c == self.rules.gear
+            && self.rules.is_symbol(*c)
+            && self.adjacent_span_ids(p).len() == self.rules.gear_neighbours
+    }
+
+    /// The product of the numbers adjacent to the given point, `None` if it is not a gear
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if the product does not fit in a u64.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is outside the schematic.
+    pub fn gear_ratio(&self, p: &Point) -> Result<Option<u64>, SolveError> {
+        if !self.is_gear(p) {
+            return Ok(None);
+        }
+        self.adjacent_span_ids(p)
+            .into_iter()
+            .try_fold(1u64, |ratio, i| ratio.checked_mul(self.spans[i].value))
+            .map(Some)
+            .ok_or_else(|| SolveError::Overflow(format!("the gear ratio at {p}")))
+    }
+
+    /// Sum of the gear ratios, the product of the numbers adjacent to each gear
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if a gear ratio or the sum does not fit in a u64.
+    pub fn calculate_gear_rations(&self) -> Result<u64, SolveError> {
+        self.symbol_points.iter().try_fold(0u64, |sum, p| {
+            let Some(ratio) = self.gear_ratio(p)? else {
+                return Ok(sum);
+            };
+            sum.checked_add(ratio)
+                .ok_or_else(|| SolveError::Overflow("the sum of the gear ratios".to_string()))
+        })
+    }
+}
+
+/// The schematic coloured for a terminal, with the given part numbers highlighted. Created by
+/// [`Schematic::render`].
+pub struct Rendering<'a> {
+    schematic: &'a Schematic,
+    parts: &'a PartNumbers,
+}
+
+impl<'a> Rendering<'a> {
+    /// The schematic being rendered
+    pub fn schematic(&self) -> &'a Schematic {
+        self.schematic
+    }
+
+    /// The part numbers highlighted in the rendering
+    pub fn parts(&self) -> &'a PartNumbers {
+        self.parts
+    }
+}
+
+impl Schematic {
+    /// Colour the schematic for a terminal: counted numbers in green, other numbers in blue,
+    /// gears in yellow and other symbols in red
+    pub fn render<'a>(&'a self, parts: &'a PartNumbers) -> Rendering<'a> {
+        Rendering {
+            schematic: self,
+            parts,
+        }
+    }
+}
+
+impl std::fmt::Display for Rendering<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Rendering { schematic, parts } = self;
+        for (i, row) in schematic.array.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                if let Some(span) = schematic.span_at(&Point { x: j, y: i }) {
+                    if parts.contains(span) {
+                        write!(f, "{}", c.to_string().green())?;
+                    } else {
+                        write!(f, "{}", c.to_string().blue())?;
+                    }
+                } else if schematic.rules.is_symbol(*c) {
+                    if schematic.is_gear(&Point { x: j, y: i }) {
+                        write!(f, "{}", c.to_string().yellow())?;
+                    } else {
+                        write!(f, "{}", c.to_string().red())?;
+                    }
+                } else {
+                    write!(f, "{}", c)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Renders with the part numbers of the schematic itself
+impl std::fmt::Display for Schematic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(&self.part_numbers()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Connectivity;
+
+    #[test]
+    fn test_create_2d_array() {
+        let input = "123\n456\n789";
+        let expected = vec![
+            vec!['1', '2', '3'],
+            vec!['4', '5', '6'],
+            vec!['7', '8', '9'],
+        ];
+        assert_eq!(create_2d_array(input), expected);
+
+        let input = "1..#\n4...\n%*3.";
+        let expected = vec![
+            vec!['1', '.', '.', '#'],
+            vec!['4', '.', '.', '.'],
+            vec!['%', '*', '3', '.'],
+        ];
+        assert_eq!(create_2d_array(input), expected);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "467..114..\n...*......\n..99999999999999999999.";
+        let err = Schematic::parse(input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected a number that fits in a u64, found `99999999999999999999`"
+        );
+
+        // The widest number that fits still parses
+        let schematic = Schematic::parse("18446744073709551615*").unwrap();
+        assert_eq!(schematic.spans()[0].value, u64::MAX);
+    }
+
+    #[test]
+    fn test_non_ascii_digits() {
+        // Digits outside ASCII are symbols, not parts of a number
+        let schematic = Schematic::parse("1*½").unwrap();
+        let values: Vec<u64> = schematic.spans().iter().map(|span| span.value).collect();
+        assert_eq!(values, vec![1]);
+        assert_eq!(
+            schematic.symbol_points(),
+            &[Point { x: 1, y: 0 }, Point { x: 2, y: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let input = "467..114..\n...*......\n..35..633.";
+        let schematic = Schematic::parse(input).unwrap();
+        let values: Vec<u64> = schematic.spans().iter().map(|span| span.value).collect();
+        assert_eq!(values, vec![467, 114, 35, 633]);
+        assert_eq!(
+            schematic.span_at(&Point { x: 7, y: 2 }),
+            Some(&Span {
+                row: 2,
+                start: 6,
+                end: 9,
+                value: 633
+            })
+        );
+        assert_eq!(schematic.span_at(&Point { x: 3, y: 1 }), None);
+        assert_eq!(schematic.span_at(&Point { x: 20, y: 0 }), None);
+        assert_eq!(schematic.symbol_points(), &[Point { x: 3, y: 1 }]);
+
+        let adjacent: Vec<u64> = schematic
+            .adjacent_spans(&Point { x: 3, y: 1 })
+            .iter()
+            .map(|span| span.value)
+            .collect();
+        assert_eq!(adjacent, vec![467, 35]);
+        assert_eq!(
+            schematic.adjacent_symbols(&schematic.spans()[0]),
+            vec![Point { x: 3, y: 1 }]
+        );
+        assert!(schematic.adjacent_symbols(&schematic.spans()[1]).is_empty());
+        assert_eq!(schematic.get_number(&Point { x: 1, y: 0 }), 67);
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        let input = "18446744073709551615\n#...................\n4294967296*2";
+        let schematic = Schematic::parse(input).unwrap();
+        assert_eq!(schematic.spans()[0].value, u64::MAX);
+        assert_eq!(schematic.get_number(&Point { x: 10, y: 0 }), 3709551615);
+        assert_eq!(
+            schematic.gear_ratio(&Point { x: 10, y: 2 }),
+            Ok(Some(8589934592))
+        );
+        assert_eq!(schematic.gear_ratio(&Point { x: 0, y: 1 }), Ok(None));
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let schematic: Schematic = "18446744073709551615\n#1".into();
+        let overflow = SolveError::Overflow("the sum of the part numbers".to_string());
+        assert_eq!(schematic.calculate_partnumbers(), Err(overflow.clone()));
+        assert_eq!(schematic.part_numbers().sum(), Err(overflow));
+
+        let schematic: Schematic = "18446744073709551615*1.1*1".into();
+        assert_eq!(
+            schematic.calculate_gear_rations(),
+            Err(SolveError::Overflow(
+                "the sum of the gear ratios".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_gear_ratio_overflow() {
+        let schematic: Schematic = "4294967296*4294967296".into();
+        let overflow = SolveError::Overflow("the gear ratio at (10, 0)".to_string());
+        assert_eq!(
+            schematic.gear_ratio(&Point { x: 10, y: 0 }),
+            Err(overflow.clone())
+        );
+        assert_eq!(schematic.calculate_gear_rations(), Err(overflow));
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        let input = "467\n...*\n..35......633\n......#";
+        let schematic = Schematic::parse(input).unwrap();
+        assert_eq!(schematic.get(&Point { x: 5, y: 0 }), None);
+        assert_eq!(schematic.span_at(&Point { x: 10, y: 0 }), None);
+        assert_eq!(schematic.calculate_partnumbers(), Ok(467 + 35));
+        assert_eq!(schematic.calculate_gear_rations(), Ok(467 * 35));
+        assert_eq!(schematic.report().lone_numbers.len(), 1);
+
+        // Symbols past the end of a shorter row still reach longer neighbours
+        let schematic = Schematic::parse("1\n..........*\n.........12").unwrap();
+        assert_eq!(schematic.calculate_partnumbers(), Ok(12));
+    }
+
+    #[test]
+    fn test_crlf() {
+        let lf = Schematic::parse("467..114..\n...*......\n..35..633.\n").unwrap();
+        let crlf = Schematic::parse("467..114..\r\n...*......\r\n..35..633.\r\n").unwrap();
+        assert_eq!(crlf.spans(), lf.spans());
+        assert_eq!(crlf.symbol_points(), lf.symbol_points());
+        assert_eq!(crlf.rows().map(<[char]>::len).max(), Some(10));
+        assert_eq!(crlf.calculate_partnumbers(), Ok(467 + 35));
+        assert_eq!(crlf.calculate_gear_rations(), Ok(16345));
+    }
+
+    #[test]
+    fn test_get_adjacent_numbers() {
+        let input = "123\n456\n789";
+        let schematic: Schematic = input.into();
+        assert_eq!(
+            schematic.get_adjacent_numbers(&Point { x: 0, y: 0 }),
+            vec![Point { x: 0, y: 1 }, Point { x: 0, y: 0 }]
+        );
+        assert_eq!(
+            schematic.get_adjacent_numbers(&Point { x: 1, y: 1 }),
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 0, y: 2 }
+            ]
+        );
+        assert_eq!(
+            schematic.get_adjacent_numbers(&Point { x: 2, y: 2 }),
+            vec![Point { x: 0, y: 1 }, Point { x: 0, y: 2 }]
+        );
+    }
+
+    #[test]
+    fn test_get_beginning_of_number() {
+        let input = "123\n456\n789";
+        let schematic: Schematic = input.into();
+        assert_eq!(
+            schematic.get_beginning_of_number(&Point { x: 0, y: 0 }),
+            Point { x: 0, y: 0 }
+        );
+        assert_eq!(
+            schematic.get_beginning_of_number(&Point { x: 1, y: 1 }),
+            Point { x: 0, y: 1 }
+        );
+
+        let input = ".23\n4/6\n78%";
+        let schematic: Schematic = input.into();
+        assert_eq!(
+            schematic.get_beginning_of_number(&Point { x: 2, y: 0 }),
+            Point { x: 1, y: 0 }
+        );
+        assert_eq!(
+            schematic.get_beginning_of_number(&Point { x: 1, y: 1 }),
+            Point { x: 2, y: 1 }
+        );
+        assert_eq!(
+            schematic.get_beginning_of_number(&Point { x: 1, y: 2 }),
+            Point { x: 0, y: 2 }
+        );
+    }
+
+    #[test]
+    fn test_calculate_partnumbers() {
+        let input = "10.\n/..\n...";
+        let schematic: Schematic = input.into();
+        assert_eq!(schematic.calculate_partnumbers(), Ok(10));
+
+        let input = "1..#\n4...\n%*3.";
+        let schematic: Schematic = input.into();
+        assert_eq!(schematic.calculate_partnumbers(), Ok(7));
+        // Counting leaves the schematic as it was
+        assert_eq!(schematic.calculate_partnumbers(), Ok(7));
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        let input = "1..#\n4...\n%*3.\n...9";
+        let schematic: Schematic = input.into();
+        let parts = schematic.part_numbers();
+        let values: Vec<u64> = parts.iter().map(|span| span.value).collect();
+        assert_eq!(values, vec![4, 3]);
+        assert!(parts.contains(&schematic.spans()[1]));
+        assert!(!parts.contains(&schematic.spans()[0]));
+        assert!(!parts.contains(&schematic.spans()[3]));
+        assert_eq!(parts.sum(), Ok(7));
+        assert_eq!(schematic.part_numbers(), parts);
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = "1...\n.*2.\n..3.";
+        let schematic: Schematic = input.into();
+        assert_eq!(schematic.calculate_partnumbers(), Ok(6));
+        assert_eq!(schematic.calculate_gear_rations(), Ok(0));
+
+        let three_way = Rules {
+            gear_neighbours: 3,
+            ..Rules::default()
+        };
+        assert_eq!(
+            schematic.with_rules(three_way).calculate_gear_rations(),
+            Ok(6)
+        );
+
+        let four = Rules {
+            connectivity: Connectivity::Four,
+            ..Rules::default()
+        };
+        let schematic = schematic.with_rules(four);
+        assert_eq!(schematic.calculate_partnumbers(), Ok(2));
+        assert_eq!(schematic.adjacent_spans(&Point { x: 2, y: 2 }).len(), 1);
+
+        let input = "1_..\n__2_\n#3__";
+        let dots = Rules {
+            symbols: Some(HashSet::from(['.'])),
+            blank: '_',
+            ..Rules::default()
+        };
+        let schematic = Schematic::parse_with_rules(input, dots).unwrap();
+        assert_eq!(schematic.symbol_points().len(), 2);
+        assert!(!schematic.is_symbol(&Point { x: 0, y: 2 }));
+        assert_eq!(schematic.calculate_partnumbers(), Ok(2));
+    }
+
+    #[test]
+    fn test_is_gear() {
+        let input = ".1..\n.*..\n1...";
+        let schematic: Schematic = input.into();
+        assert!(schematic.is_gear(&Point { x: 1, y: 1 }));
+
+        let input = ".10.\n.*..\n1...";
+        let schematic: Schematic = input.into();
+        assert!(schematic.is_gear(&Point { x: 1, y: 1 }));
+
+        let input = "....\n.*..\n1...";
+        let schematic: Schematic = input.into();
+        assert!(!schematic.is_gear(&Point { x: 1, y: 1 }));
+    }
+}
@@ -0,0 +1,11 @@
+use common::Solution;
+use day_3::Schematic;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let schematic = Schematic::parse(EXAMPLE).unwrap();
+    assert_eq!(schematic.part1(), Ok(4361));
+    assert_eq!(schematic.part2(), Ok(467835));
+}
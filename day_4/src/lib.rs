@@ -0,0 +1,458 @@
+//! Day 4: Scratchcards
+//!
+//! Score the scratchcards and count how many cards are won in total.
+
+use common::{parse_number, Location, ParseError, Solution, SolveError};
+use scanf::sscanf;
+use std::collections::{HashMap, HashSet};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// All scratchcards, one card per line
+#[derive(Debug)]
+pub struct Deck {
+    pub cards: Vec<Card>,
+}
+
+/// The outcome of playing out all won copies of a deck, as found by [`Deck::cascade`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    /// The id of each card of the deck and how many instances of it are held, the original
+    /// included, in deck order
+    pub copies: Vec<(u32, u64)>,
+    /// Every card that won copies of another card, in order of increasing winning card id
+    pub triggers: Vec<Trigger>,
+    /// The total number of cards held
+    pub total: u64,
+}
+
+/// Copies of one card won by the instances of another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trigger {
+    /// The id of the winning card
+    pub from: u32,
+    /// The id of the card copied
+    pub to: u32,
+    /// The number of copies won, one for each instance of the winning card
+    pub copies: u64,
+}
+
+impl Cascade {
+    /// How many instances of the card with the given id are held, `None` if there is no such
+    /// card
+    pub fn copies_of(&self, id: u32) -> Option<u64> {
+        self.copies
+            .iter()
+            .find(|(card, _)| *card == id)
+            .map(|(_, copies)| *copies)
+    }
+
+    /// The copies won by the card with the given id
+    pub fn triggered_by(&self, id: u32) -> impl Iterator<Item = &Trigger> {
+        self.triggers
+            .iter()
+            .filter(move |trigger| trigger.from == id)
+    }
+}
+
+impl Deck {
+    /// Play out the won copies: each instance of a card wins one copy of each card in
+    /// [`Card::get_won_ids`]. Cards are looked up by id, so the deck may be unsorted and have
+    /// gaps between ids. Won ids missing from the deck win nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if the number of cards does not fit in a u64.
+    pub fn cascade(&self) -> Result<Cascade, SolveError> {
+        let overflow = || SolveError::Overflow("the number of cards".to_string());
+
+        let mut by_id: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (index, card) in self.cards.iter().enumerate() {
+            by_id.entry(card.id).or_default().push(index);
+        }
+
+        // Copies only go to cards with higher ids, so going through the cards by increasing id
+        // finishes the copies of each card before it is played
+        let mut order: Vec<usize> = (0..self.cards.len()).collect();
+        order.sort_by_key(|&index| self.cards[index].id);
+
+        let mut copies = vec![1u64; self.cards.len()];
+        let mut triggers = Vec::new();
+        for index in order {
+            let card = &self.cards[index];
+            let held = copies[index];
+            for won_id in card.get_won_ids() {
+                for &won in by_id.get(&won_id).into_iter().flatten() {
+                    copies[won] = copies[won].checked_add(held).ok_or_else(overflow)?;
+                    triggers.push(Trigger {
+                        from: card.id,
+                        to: won_id,
+                        copies: held,
+                    });
+                }
+            }
+        }
+
+        let total = copies
+            .iter()
+            .try_fold(0u64, |total, &held| total.checked_add(held))
+            .ok_or_else(overflow)?;
+        Ok(Cascade {
+            copies: self.cards.iter().map(|card| card.id).zip(copies).collect(),
+            triggers,
+            total,
+        })
+    }
+}
+
+impl Solution for Deck {
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let cards = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Card::new(line).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { cards })
+    }
+
+    // Total score of all cards
+    fn part1(&self) -> Result<u32, SolveError> {
+        self.cards.iter().try_fold(0u32, |total, card| {
+            total
+                .checked_add(card.calculate_score()?)
+                .ok_or_else(|| SolveError::Overflow("the total score".to_string()))
+        })
+    }
+
+    // Total number of scratchcards held after all won copies have been processed
+    fn part2(&self) -> Result<u64, SolveError> {
+        Ok(self.cascade()?.total)
+    }
+}
+
+fn seperate_card_id(card: &str) -> Result<(u32, &str), ParseError> {
+    let Some((header, rest)) = card.split_once(':') else {
+        return Err(ParseError::unexpected("`:`", Location::end_of(card)));
+    };
+
+    let mut id: u32 = 0;
+    if sscanf!(header, "Card {}", id).is_err() {
+        return Err(ParseError::unexpected(
+            "`Card <id>`",
+            Location::of(card, header),
+        ));
+    }
+    Ok((id, rest))
+}
+
+fn seperate_card_numbers<'a>(line: &str, card: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let Some((winning_numbers, numbers)) = card.split_once('|') else {
+        return Err(ParseError::unexpected("`|`", Location::end_of(line)));
+    };
+
+    Ok((winning_numbers.trim(), numbers.trim()))
+}
+
+fn parse_winning_numbers(line: &str, numbers: &str) -> Result<HashSet<u32>, ParseError> {
+    let mut winning_numbers = HashSet::new();
+
+    for number in numbers.split_whitespace() {
+        winning_numbers.insert(parse_number(line, number)?);
+    }
+    Ok(winning_numbers)
+}
+
+fn parse_numbers(line: &str, numbers: &str) -> Result<Vec<u32>, ParseError> {
+    let mut numbers_vec = Vec::new();
+
+    for number in numbers.split_whitespace() {
+        numbers_vec.push(parse_number(line, number)?);
+    }
+    Ok(numbers_vec)
+}
+
+/// A scratchcard with its winning numbers and the numbers on the card
+#[derive(Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning_numbers: HashSet<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    /// Parse a card of the form `Card 1: 41 48 83 | 83 86 6`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the card is malformed.
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let (card_number, rest) = seperate_card_id(s)?;
+        let (winning_numbers, numbers) = seperate_card_numbers(s, rest)?;
+
+        let winning_numbers = parse_winning_numbers(s, winning_numbers)?;
+        let numbers = parse_numbers(s, numbers)?;
+
+        Ok(Self {
+            id: card_number,
+            winning_numbers,
+            numbers,
+        })
+    }
+
+    /// Whether the number is one of the winning numbers of the card
+    pub fn is_winning_number(&self, number: u32) -> bool {
+        self.winning_numbers.contains(&number)
+    }
+
+    /// The score of the card: 1 for the first matching number, doubled for each further match
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::Overflow`] if the score does not fit in a u32.
+    pub fn calculate_score(&self) -> Result<u32, SolveError> {
+        let mut score: u32 = 0;
+        for number in &self.numbers {
+            if self.is_winning_number(*number) {
+                if score == 0 {
+                    score = 1;
+                } else {
+                    score = score.checked_mul(2).ok_or_else(|| {
+                        SolveError::Overflow(format!("the score of card {}", self.id))
+                    })?;
+                }
+            }
+        }
+        Ok(score)
+    }
+
+    /// The number of numbers on the card that are winning numbers
+    pub fn get_winning_number_count(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|&n| self.is_winning_number(*n))
+            .count() as u32
+    }
+
+    /// The ids of the cards won by this card, the cards directly following it. Ids past
+    /// `u32::MAX` cannot exist and are left out.
+    pub fn get_won_ids(&self) -> Vec<u32> {
+        let won_count = self.get_winning_number_count();
+
+        (1..=won_count)
+            .map_while(|i| self.id.checked_add(i))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seperate_card_id() {
+        let (id, rest) = seperate_card_id("Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(rest, " 1 2 3 | 4 5 6");
+    }
+
+    #[test]
+    fn test_seperate_card_numbers() {
+        let card = "1 2 3 | 4 5 6";
+        let (winning_numbers, numbers) = seperate_card_numbers(card, card).unwrap();
+        assert_eq!(winning_numbers, "1 2 3");
+        assert_eq!(numbers, "4 5 6");
+    }
+
+    #[test]
+    fn test_parse_winning_numbers() {
+        let winning_numbers = parse_winning_numbers("1 2 3", "1 2 3").unwrap();
+        assert_eq!(winning_numbers.len(), 3);
+        assert!(winning_numbers.contains(&1));
+        assert!(winning_numbers.contains(&2));
+        assert!(winning_numbers.contains(&3));
+        assert!(!winning_numbers.contains(&4));
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let numbers = parse_numbers("1 2 3", "1 2 3").unwrap();
+        assert_eq!(numbers.len(), 3);
+        assert_eq!(numbers[0], 1);
+        assert_eq!(numbers[1], 2);
+        assert_eq!(numbers[2], 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Card::new("Card 1: 1 2 3 | 4 x 6").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 19: invalid number `x`");
+
+        let err = Card::new("Card 1: 1 2 3 4 5 6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 20: expected `|`, found the end of the line"
+        );
+
+        let err = Deck::parse("Card 1: 1 | 2\nCrad 2: 1 | 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected `Card <id>`, found `Crad 2`"
+        );
+    }
+
+    #[test]
+    fn test_is_winning_number() {
+        let card = Card::new("Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert!(card.is_winning_number(1));
+        assert!(card.is_winning_number(2));
+        assert!(card.is_winning_number(3));
+        assert!(!card.is_winning_number(4));
+        assert!(!card.is_winning_number(5));
+        assert!(!card.is_winning_number(6));
+    }
+
+    #[test]
+    fn test_calculate_score() {
+        let card = Card::new("Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert_eq!(card.calculate_score(), Ok(0));
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 4").unwrap();
+        assert_eq!(card.calculate_score(), Ok(2));
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 3 4").unwrap();
+        assert_eq!(card.calculate_score(), Ok(4));
+    }
+
+    #[test]
+    fn test_score_overflow() {
+        // 32 matches score 2^31, the most a u32 holds
+        let numbers: Vec<String> = (1..=33).map(|n| n.to_string()).collect();
+        let card = Card::new(&format!(
+            "Card 7: {} | {}",
+            numbers[..32].join(" "),
+            numbers[..32].join(" ")
+        ))
+        .unwrap();
+        assert_eq!(card.calculate_score(), Ok(1 << 31));
+
+        let card = Card::new(&format!(
+            "Card 7: {} | {}",
+            numbers.join(" "),
+            numbers.join(" ")
+        ))
+        .unwrap();
+        let overflow = SolveError::Overflow("the score of card 7".to_string());
+        assert_eq!(card.calculate_score(), Err(overflow));
+
+        // Two cards scoring 2^31 each add up past u32::MAX
+        let line = format!("{} | {}", numbers[..32].join(" "), numbers[..32].join(" "));
+        let deck = Deck::parse(&format!("Card 1: {line}\nCard 2: {line}")).unwrap();
+        let overflow = SolveError::Overflow("the total score".to_string());
+        assert_eq!(deck.part1(), Err(overflow));
+    }
+
+    #[test]
+    fn test_get_winning_number_count() {
+        let card = Card::new("Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert_eq!(card.get_winning_number_count(), 0);
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 4").unwrap();
+        assert_eq!(card.get_winning_number_count(), 2);
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 3 4").unwrap();
+        assert_eq!(card.get_winning_number_count(), 3);
+    }
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_cascade() {
+        let deck = Deck::parse(EXAMPLE).unwrap();
+        let cascade = deck.cascade().unwrap();
+        assert_eq!(
+            cascade.copies,
+            vec![(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]
+        );
+        assert_eq!(cascade.total, 30);
+        assert_eq!(cascade.copies_of(4), Some(8));
+        assert_eq!(cascade.copies_of(7), None);
+
+        let won: Vec<(u32, u64)> = cascade
+            .triggered_by(3)
+            .map(|trigger| (trigger.to, trigger.copies))
+            .collect();
+        assert_eq!(won, vec![(4, 4), (5, 4)]);
+        assert_eq!(cascade.triggered_by(5).count(), 0);
+        assert_eq!(cascade.triggers.len(), 9);
+    }
+
+    #[test]
+    fn test_cascade_unsorted() {
+        let mut lines: Vec<&str> = EXAMPLE.lines().collect();
+        lines.reverse();
+        lines.swap(1, 4);
+        let deck = Deck::parse(&lines.join("\n")).unwrap();
+        let cascade = deck.cascade().unwrap();
+        assert_eq!(cascade.total, 30);
+        assert_eq!(cascade.copies_of(5), Some(14));
+        assert_eq!(cascade.copies[0], (6, 1));
+    }
+
+    #[test]
+    fn test_cascade_gaps() {
+        // Card 10 wins copies of 11 and 12, but there is no card 11
+        let deck = Deck::parse("Card 12: 1 | 2\nCard 10: 1 2 | 1 2\nCard 3: 1 | 1").unwrap();
+        let cascade = deck.cascade().unwrap();
+        assert_eq!(cascade.copies, vec![(12, 2), (10, 1), (3, 1)]);
+        assert_eq!(cascade.total, 4);
+        assert_eq!(
+            cascade.triggers,
+            vec![Trigger {
+                from: 10,
+                to: 12,
+                copies: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cascade_overflow() {
+        // Each card wins copies of the next ten, so the copies nearly double with every card
+        let input: Vec<String> = (1..=80)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10"))
+            .collect();
+        let deck = Deck::parse(&input.join("\n")).unwrap();
+        let overflow = SolveError::Overflow("the number of cards".to_string());
+        assert_eq!(deck.cascade(), Err(overflow.clone()));
+        assert_eq!(deck.part2(), Err(overflow));
+    }
+
+    #[test]
+    fn test_get_won_ids() {
+        let card = Card::new("Card 1: 1 2 3 | 4 5 6").unwrap();
+        assert_eq!(card.get_won_ids().len(), 0);
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 4").unwrap();
+        assert_eq!(card.get_won_ids(), vec![2, 3]);
+
+        let card = Card::new("Card 1: 1 2 3 | 1 2 3 4").unwrap();
+        assert_eq!(card.get_won_ids(), vec![2, 3, 4]);
+
+        let card = Card::new("Card 4294967294: 1 2 | 1 2").unwrap();
+        assert_eq!(card.get_won_ids(), vec![u32::MAX]);
+
+        let deck = Deck::parse("Card 4294967295: 1 | 1").unwrap();
+        assert_eq!(deck.cards[0].get_won_ids(), vec![]);
+        assert_eq!(deck.part2(), Ok(1));
+    }
+}
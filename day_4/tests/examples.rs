@@ -0,0 +1,11 @@
+use common::Solution;
+use day_4::Deck;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let deck = Deck::parse(EXAMPLE).unwrap();
+    assert_eq!(deck.part1(), Ok(13));
+    assert_eq!(deck.part2(), Ok(30));
+}
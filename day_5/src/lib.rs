@@ -0,0 +1,847 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! Follow the almanac's maps to find the location of each seed.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+use common::{parse_number, Location, ParseError, Solution, SolveError};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The seeds to plant and the maps leading from a seed to its location
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// The seeds read as ranges, see [`get_seeds_as_ranges`]
+    pub seed_ranges: Vec<(u64, u64)>,
+    /// The maps in the order they are applied, from seed to location
+    pub maps: Vec<Map>,
+    /// The maps composed into one, mapping a seed directly to the last category, its location
+    pub seed_to_location: Map,
+}
+
+impl Solution for Almanac {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let maps = create_maps(input)?;
+        Ok(Self {
+            seeds: get_seeds(input)?,
+            seed_ranges: get_seeds_as_ranges(input)?,
+            seed_to_location: compose_maps(&maps),
+            maps,
+        })
+    }
+
+    // The smallest location of any of the listed seeds
+    fn part1(&self) -> Result<u64, SolveError> {
+        self.seeds
+            .iter()
+            .map(|seed| self.seed_to_location.map(*seed))
+            .min()
+            .ok_or_else(|| SolveError::Unsolvable("there are no seeds".to_string()))
+    }
+
+    // The smallest location of any seed in the listed seed ranges
+    fn part2(&self) -> Result<u64, SolveError> {
+        self.seed_ranges
+            .iter()
+            .flat_map(|range| self.seed_to_location.map_range(*range))
+            .map(|(start, _)| start)
+            .min()
+            .ok_or_else(|| SolveError::Unsolvable("there are no seed ranges".to_string()))
+    }
+}
+
+impl Almanac {
+    /// Convert a value of one category to another, following the maps forwards or backwards.
+    /// Returns `None` if either category is not in the almanac.
+    #[must_use]
+    pub fn convert(&self, value: u64, from: &str, to: &str) -> Option<u64> {
+        let from = self.category_index(from)?;
+        let to = self.category_index(to)?;
+
+        if from <= to {
+            Some(map_seed(value, &self.maps[from..to]))
+        } else {
+            Some(map_location(value, &self.maps[to..from]))
+        }
+    }
+
+    // The position of the category in the chain of maps, `seed` being 0
+    fn category_index(&self, category: &str) -> Option<usize> {
+        if category == "seed" {
+            return Some(0);
+        }
+        self.maps
+            .iter()
+            .position(|map| map.destination == category)
+            .map(|index| index + 1)
+    }
+}
+
+/// Get the location of a seed
+#[must_use]
+pub fn map_seed(seed: u64, maps: &[Map]) -> u64 {
+    let mut mapped_value = seed;
+    for map in maps {
+        mapped_value = map.map(mapped_value);
+    }
+    mapped_value
+}
+
+/// Get the ranges of locations of a range of seeds, inclusive at both ends
+#[must_use]
+pub fn map_seed_range(seeds: (u64, u64), maps: &[Map]) -> Vec<(u64, u64)> {
+    let mut mapped_ranges = vec![seeds];
+    for map in maps {
+        mapped_ranges = mapped_ranges
+            .into_iter()
+            .flat_map(|range| map.map_range(range))
+            .collect();
+    }
+    mapped_ranges
+}
+
+/// Get the seed of a location
+#[must_use]
+pub fn map_location(location: u64, maps: &[Map]) -> u64 {
+    let mut mapped_value = location;
+
+    let maps_reverse = maps.iter().rev().collect::<Vec<&Map>>();
+
+    for map in maps_reverse {
+        mapped_value = map.map_reverse(mapped_value);
+    }
+    mapped_value
+}
+
+// The first line of the almanac and the seed numbers listed on it
+fn seed_line(input: &str) -> Result<(&str, &str), ParseError> {
+    let line = input.lines().next().ok_or(ParseError::UnexpectedEnd {
+        expected: "the `seeds:` line",
+        line: 1,
+    })?;
+
+    let seeds = line
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::unexpected("`seeds: `", Location::of(line, line)))?;
+
+    Ok((line, seeds))
+}
+
+/// Returns a vector of ranges of valid seeds. The ranges are tuples of (start, end), inclusive
+/// at both ends. Ranges of length 0 hold no seed and are left out.
+///
+/// # Errors
+///
+/// Returns an error if the first line does not list pairs of seed numbers, or if a range ends
+/// past `u64::MAX`.
+pub fn get_seeds_as_ranges(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let (line, seed_str) = seed_line(input)?;
+
+    let seed_values = seed_str
+        .split_ascii_whitespace()
+        .map(|s| parse_number(line, s).map(|value| (s, value)))
+        .collect::<Result<Vec<(&str, u64)>, _>>()?;
+
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    for chunk in seed_values.chunks(2) {
+        let (range_start, (length_str, range_length)) = match chunk {
+            [(_, start), length] => (*start, *length),
+            _ => {
+                return Err(ParseError::unexpected(
+                    "a range length",
+                    Location::end_of(line),
+                ))
+            }
+        };
+
+        if range_length == 0 {
+            continue;
+        }
+        let range_end = range_start.checked_add(range_length - 1).ok_or_else(|| {
+            ParseError::unexpected(
+                "a range length that stays within u64",
+                Location::of(line, length_str),
+            )
+        })?;
+
+        ranges.push((range_start, range_end));
+    }
+
+    Ok(ranges)
+}
+
+/// The seeds listed on the first line of the almanac
+///
+/// # Errors
+///
+/// Returns an error if the first line is not a list of seed numbers.
+pub fn get_seeds(input: &str) -> Result<Vec<u64>, ParseError> {
+    let (line, seeds) = seed_line(input)?;
+
+    seeds
+        .split_ascii_whitespace()
+        .map(|s| parse_number(line, s))
+        .collect()
+}
+
+fn is_blank_line(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Parse the maps following the seeds, in the order they appear. Each map starts with a
+/// header such as `seed-to-soil map:`, and must lead on from the category the previous map
+/// ended at, starting with `seed`.
+///
+/// # Errors
+///
+/// Returns an error if a header or a range is malformed, or if the maps do not form a chain
+/// of categories starting at `seed`.
+pub fn create_maps(input: &str) -> Result<Vec<Map>, ParseError> {
+    // Skip the seeds
+    let iter = input.lines().enumerate().skip(1);
+
+    let mut maps: Vec<Map> = Vec::new();
+    let mut current: Option<Map> = None;
+    for (i, line) in iter {
+        if is_blank_line(line) {
+            continue;
+        }
+
+        if line.ends_with("map:") {
+            maps.extend(current.take());
+
+            let (source, destination) = parse_header(line).map_err(|err| err.at_line(i + 1))?;
+            let expected_source = maps.last().map_or("seed", |map| map.destination.as_str());
+            if source != expected_source {
+                let expected = if maps.is_empty() {
+                    "a map from `seed`"
+                } else {
+                    "a map from the category the previous map ended at"
+                };
+                return Err(
+                    ParseError::unexpected(expected, Location::of(line, source)).at_line(i + 1)
+                );
+            }
+            if destination == "seed" || maps.iter().any(|map| map.destination == destination) {
+                return Err(ParseError::unexpected(
+                    "a category not mapped to before",
+                    Location::of(line, destination),
+                )
+                .at_line(i + 1));
+            }
+
+            current = Some(Map::new(source, destination, Vec::new()));
+        } else {
+            let Some(map) = current.as_mut() else {
+                return Err(ParseError::unexpected(
+                    "a header such as `seed-to-soil map:`",
+                    Location::of(line, line),
+                )
+                .at_line(i + 1));
+            };
+            let range = Range::try_from(line).map_err(|err| err.at_line(i + 1))?;
+            map.insert(&range);
+        }
+    }
+    maps.extend(current);
+
+    Ok(maps)
+}
+
+// Parse a map header of the form `seed-to-soil map:` into its source and destination
+fn parse_header(line: &str) -> Result<(&str, &str), ParseError> {
+    line.strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+        .ok_or_else(|| {
+            ParseError::unexpected(
+                "a header such as `seed-to-soil map:`",
+                Location::of(line, line),
+            )
+        })
+}
+
+/// A line of a map, mapping `range_length` values starting at `source_start` to the values
+/// starting at `destination_start`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_field_names)]
+pub struct Range {
+    pub destination_start: u64,
+    pub source_start: u64,
+    pub range_length: u64,
+}
+
+impl Range {
+    // The last source value covered by the range
+    fn source_end(&self) -> u64 {
+        self.source_start + (self.range_length - 1)
+    }
+}
+
+impl TryFrom<&str> for Range {
+    type Error = ParseError;
+
+    // Parse a line of the form `<destination start> <source start> <length>`, where neither
+    // the source nor the destination may end past `u64::MAX`
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        let mut parts = value.split_ascii_whitespace();
+        let mut next_number = || match parts.next() {
+            Some(part) => parse_number::<u64>(value, part).map(|number| (part, number)),
+            None => Err(ParseError::unexpected(
+                "three numbers",
+                Location::end_of(value),
+            )),
+        };
+
+        let (_, destination_start) = next_number()?;
+        let (_, source_start) = next_number()?;
+        let (length_str, range_length) = next_number()?;
+
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::unexpected(
+                "the end of the line",
+                Location::of(value, extra),
+            ));
+        }
+
+        let last_offset = range_length.saturating_sub(1);
+        if source_start.checked_add(last_offset).is_none()
+            || destination_start.checked_add(last_offset).is_none()
+        {
+            return Err(ParseError::unexpected(
+                "a range length that stays within u64",
+                Location::of(value, length_str),
+            ));
+        }
+
+        Ok(Range {
+            destination_start,
+            source_start,
+            range_length,
+        })
+    }
+}
+
+/// A map from one category to the next. Values not covered by any range map to themselves.
+///
+/// Where ranges overlap, the one added first wins, as when the ranges are tried in the order
+/// they are listed. The ranges are trimmed to the values they win and kept sorted by their
+/// source start, so a value is looked up with a binary search. The same is done for the
+/// destinations, to look up a value in reverse.
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+    /// The category mapped from, e.g. `seed`
+    pub source: String,
+    /// The category mapped to, e.g. `soil`
+    pub destination: String,
+    ranges: Vec<Range>,
+    // The ranges with their source and destination swapped
+    reverse: Vec<Range>,
+}
+
+impl Map {
+    /// Create a map between two categories from its ranges. Where ranges overlap, the earlier
+    /// one in `ranges` wins. Empty ranges are left out.
+    #[must_use]
+    pub fn new(source: &str, destination: &str, ranges: Vec<Range>) -> Self {
+        let mut map = Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            ..Self::default()
+        };
+        for range in ranges {
+            map.insert(&range);
+        }
+        map
+    }
+
+    // Add a range, keeping only the values not covered by the ranges added before it
+    fn insert(&mut self, range: &Range) {
+        for piece in insert_uncovered(&mut self.ranges, range) {
+            let swapped = Range {
+                destination_start: piece.source_start,
+                source_start: piece.destination_start,
+                range_length: piece.range_length,
+            };
+            insert_uncovered(&mut self.reverse, &swapped);
+        }
+    }
+
+    /// The ranges of the map, sorted by their source start and trimmed so that they do not
+    /// overlap
+    #[must_use]
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// Map a source value to its destination
+    #[must_use]
+    pub fn map(&self, source: u64) -> u64 {
+        lookup(&self.ranges, source)
+    }
+
+    /// Map a range of source values, inclusive at both ends, to the ranges of destinations it
+    /// covers. The range is split wherever it crosses the boundary of a [`Range`]. A range
+    /// ending before it starts is empty and maps to nothing.
+    #[must_use]
+    pub fn map_range(&self, (start, end): (u64, u64)) -> Vec<(u64, u64)> {
+        self.segments((start, end))
+            .into_iter()
+            .map(|(start, end, destination)| (destination, destination + (end - start)))
+            .collect()
+    }
+
+    /// The map equivalent to applying this map and then `next`, from the source of this map to
+    /// the destination of `next`
+    #[must_use]
+    pub fn compose(&self, next: &Map) -> Map {
+        let mut ranges = Vec::new();
+        for (start, end, destination) in self.segments((0, u64::MAX)) {
+            let destination_end = destination + (end - start);
+            for (mid_start, mid_end, final_destination) in
+                next.segments((destination, destination_end))
+            {
+                let source_start = start + (mid_start - destination);
+                // Values mapping to themselves need no range
+                if source_start != final_destination {
+                    ranges.push(Range {
+                        destination_start: final_destination,
+                        source_start,
+                        range_length: mid_end - mid_start + 1,
+                    });
+                }
+            }
+        }
+        Map::new(&self.source, &next.destination, ranges)
+    }
+
+    // Split a range of source values, inclusive at both ends, into the pieces covered by a
+    // single range or by none. Each piece is `(start, end, destination of start)`.
+    fn segments(&self, (mut start, end): (u64, u64)) -> Vec<(u64, u64, u64)> {
+        let mut segments = Vec::new();
+        if start > end {
+            return segments;
+        }
+
+        let first = self
+            .ranges
+            .partition_point(|range| range.source_end() < start);
+        for range in &self.ranges[first..] {
+            if range.source_start > end {
+                break;
+            }
+
+            // Values before the range map to themselves
+            if start < range.source_start {
+                segments.push((start, range.source_start - 1, start));
+                start = range.source_start;
+            }
+
+            let overlap_end = end.min(range.source_end());
+            segments.push((
+                start,
+                overlap_end,
+                range.destination_start + (start - range.source_start),
+            ));
+            if overlap_end == end {
+                return segments;
+            }
+            start = overlap_end + 1;
+        }
+
+        segments.push((start, end, start));
+        segments
+    }
+
+    /// Map a destination value back to its source. If several sources map to the destination,
+    /// the one of the range added first is given.
+    #[must_use]
+    pub fn map_reverse(&self, destination: u64) -> u64 {
+        lookup(&self.reverse, destination)
+    }
+}
+
+// Map a value through ranges sorted by their source start and not overlapping
+fn lookup(ranges: &[Range], source: u64) -> u64 {
+    // The last range starting at or before the source is the only one that can contain it
+    let index = ranges.partition_point(|range| range.source_start <= source);
+    match index.checked_sub(1).map(|index| &ranges[index]) {
+        Some(range) if source <= range.source_end() => {
+            range.destination_start + (source - range.source_start)
+        }
+        _ => source,
+    }
+}
+
+// Insert the pieces of the range whose sources are not covered yet into ranges sorted by their
+// source start and not overlapping, returning the pieces inserted
+fn insert_uncovered(ranges: &mut Vec<Range>, range: &Range) -> Vec<Range> {
+    let mut pieces = Vec::new();
+    if range.range_length == 0 {
+        return pieces;
+    }
+
+    let end = range.source_end();
+    let piece = |start: u64, last: u64| Range {
+        destination_start: range.destination_start + (start - range.source_start),
+        source_start: start,
+        range_length: last - start + 1,
+    };
+    // The first value not covered yet, or none once the rest of the range is covered
+    let mut next = Some(range.source_start);
+    let first = ranges.partition_point(|other| other.source_end() < range.source_start);
+    for other in &ranges[first..] {
+        let Some(start) = next else { break };
+        if other.source_start > end {
+            break;
+        }
+        if start < other.source_start {
+            pieces.push(piece(start, other.source_start - 1));
+        }
+        next = other
+            .source_end()
+            .checked_add(1)
+            .filter(|&start| start <= end);
+    }
+    if let Some(start) = next {
+        pieces.push(piece(start, end));
+    }
+
+    for piece in &pieces {
+        let index = ranges.partition_point(|other| other.source_start < piece.source_start);
+        ranges.insert(index, piece.clone());
+    }
+    pieces
+}
+
+/// Compose the maps, in the order they are applied, into a single map
+#[must_use]
+pub fn compose_maps(maps: &[Map]) -> Map {
+    match maps.split_first() {
+        Some((first, rest)) => rest
+            .iter()
+            .fold(first.clone(), |composed, map| composed.compose(map)),
+        None => Map::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_seeds() {
+        let seeds = get_seeds("seeds: 1 2 3 4 5").unwrap();
+        assert_eq!(seeds, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_create_maps() {
+        let maps = create_maps(
+            "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-fertilizer map:\n7 8 9\n10 11 12",
+        )
+        .unwrap();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[0].ranges().len(), 1);
+        assert_eq!(maps[1].ranges().len(), 2);
+        assert_eq!(maps[0].ranges()[0].destination_start, 1);
+        assert_eq!(maps[0].ranges()[0].source_start, 2);
+        assert_eq!(maps[0].ranges()[0].range_length, 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Almanac::parse(
+            "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-fertilizer map:\n7 8 9 10",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 7: expected the end of the line, found `10`"
+        );
+
+        let err = Almanac::parse(
+            "seeds: 1\n\nseed-to-soil map:\n0 18446744073709551615 2\n\nsoil-to-water map:\n1 2 3",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 24: expected a range length that stays within u64, found `2`"
+        );
+    }
+
+    #[test]
+    fn test_create_maps_errors() {
+        let err = create_maps("seeds: 1\n\nsoil-to-water map:\n1 2 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a map from `seed`, found `soil`"
+        );
+
+        let err =
+            create_maps("seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 1: expected a map from the category the previous map ended at, found `water`"
+        );
+
+        let err = create_maps("seeds: 1\n\nseed-to-soil map:\n\nsoil-to-seed map:").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 9: expected a category not mapped to before, found `seed`"
+        );
+
+        let err = create_maps("seeds: 1\n\n1 2 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a header such as `seed-to-soil map:`, found `1 2 3`"
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let almanac = Almanac::parse(include_str!("input_test.txt")).unwrap();
+        assert_eq!(almanac.convert(79, "seed", "humidity"), Some(78));
+        assert_eq!(almanac.convert(79, "seed", "location"), Some(82));
+        assert_eq!(almanac.convert(81, "fertilizer", "light"), Some(74));
+        assert_eq!(almanac.convert(82, "location", "seed"), Some(79));
+        assert_eq!(almanac.convert(79, "seed", "seed"), Some(79));
+        assert_eq!(almanac.convert(79, "seed", "weather"), None);
+
+        assert_eq!(almanac.maps[0].source, "seed");
+        assert_eq!(almanac.maps[0].destination, "soil");
+        assert_eq!(almanac.seed_to_location.destination, "location");
+    }
+
+    #[test]
+    fn test_map() {
+        let map = Map::new(
+            "a",
+            "b",
+            vec![
+                Range {
+                    destination_start: 1,
+                    source_start: 2,
+                    range_length: 3,
+                },
+                Range {
+                    destination_start: 7,
+                    source_start: 8,
+                    range_length: 9,
+                },
+                Range {
+                    destination_start: 10,
+                    source_start: 11,
+                    range_length: 12,
+                },
+            ],
+        );
+        assert_eq!(map.map(2), 1);
+        assert_eq!(map.map(3), 2);
+        assert_eq!(map.map(4), 3);
+        assert_eq!(map.map(8), 7);
+        assert_eq!(map.map(9), 8);
+        assert_eq!(map.map(10), 9);
+        assert_eq!(map.map(11), 10);
+        assert_eq!(map.map(12), 11);
+        // Out of range
+        assert_eq!(map.map(99), 99);
+    }
+
+    #[test]
+    fn test_map_range() {
+        let map = Map::new(
+            "a",
+            "b",
+            vec![
+                Range {
+                    destination_start: 50,
+                    source_start: 98,
+                    range_length: 2,
+                },
+                Range {
+                    destination_start: 52,
+                    source_start: 50,
+                    range_length: 48,
+                },
+            ],
+        );
+        // Fully inside a range
+        assert_eq!(map.map_range((79, 92)), vec![(81, 94)]);
+        // Outside all ranges
+        assert_eq!(map.map_range((10, 20)), vec![(10, 20)]);
+        // Empty
+        assert_eq!(map.map_range((5, 4)), vec![]);
+        // Split across both ranges and the unmapped values on either side
+        let mut ranges = map.map_range((40, 105));
+        ranges.sort_unstable();
+        assert_eq!(ranges, vec![(40, 49), (50, 51), (52, 99), (100, 105)]);
+    }
+
+    #[test]
+    fn test_map_unsorted() {
+        let map = Map::new(
+            "a",
+            "b",
+            vec![
+                Range {
+                    destination_start: 0,
+                    source_start: 20,
+                    range_length: 5,
+                },
+                Range {
+                    destination_start: 100,
+                    source_start: 5,
+                    range_length: 5,
+                },
+            ],
+        );
+        assert_eq!(map.ranges()[0].source_start, 5);
+        assert_eq!(map.map(4), 4);
+        assert_eq!(map.map(5), 100);
+        assert_eq!(map.map(9), 104);
+        assert_eq!(map.map(10), 10);
+        assert_eq!(map.map(24), 4);
+        assert_eq!(map.map(25), 25);
+    }
+
+    #[test]
+    fn test_map_overlapping() {
+        let maps = create_maps("seeds: 70\n\nseed-to-soil map:\n1000 0 100\n0 50 10").unwrap();
+        assert_eq!(maps[0].map(70), 1070);
+        assert_eq!(maps[0].map(55), 1055);
+        assert_eq!(maps[0].map(100), 100);
+        assert_eq!(maps[0].map_reverse(1070), 70);
+        assert_eq!(maps[0].map_reverse(5), 5);
+
+        // The first range listed that contains a value wins, in both directions
+        let listed = [(30, 10, 20), (0, 5, 10), (100, 25, 10), (12, 0, 40)];
+        let ranges = listed
+            .iter()
+            .map(|&(destination_start, source_start, range_length)| Range {
+                destination_start,
+                source_start,
+                range_length,
+            })
+            .collect();
+        let map = Map::new("a", "b", ranges);
+        for value in 0..150 {
+            let source = listed
+                .iter()
+                .find(|&&(_, start, length)| (start..start + length).contains(&value))
+                .map_or(value, |&(destination, start, _)| {
+                    destination + (value - start)
+                });
+            assert_eq!(map.map(value), source);
+            let destination = listed
+                .iter()
+                .filter(|&&(_, start, length)| (start..start + length).any(|s| map.map(s) == value))
+                .find(|&&(destination, _, length)| {
+                    (destination..destination + length).contains(&value)
+                })
+                .map_or(value, |&(destination, start, _)| {
+                    start + (value - destination)
+                });
+            assert_eq!(map.map_reverse(value), destination);
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        let maps =
+            create_maps("seeds: 1\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15")
+                .unwrap();
+        let composed = maps[0].compose(&maps[1]);
+        for value in 0..150 {
+            assert_eq!(composed.map(value), maps[1].map(maps[0].map(value)));
+        }
+
+        let composed = compose_maps(&maps);
+        assert_eq!(composed.map(79), map_seed(79, &maps));
+        assert_eq!(compose_maps(&[]).map(79), 79);
+    }
+
+    #[test]
+    fn test_range_from() {
+        let range = Range::try_from("1 2 3").unwrap();
+        assert_eq!(range.destination_start, 1);
+        assert_eq!(range.source_start, 2);
+        assert_eq!(range.range_length, 3);
+
+        let err = Range::try_from("1 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected three numbers, found the end of the line"
+        );
+
+        let err = Range::try_from("1 -2 3").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: invalid number `-2`");
+
+        // Ranges may end at u64::MAX, but not past it
+        let range = Range::try_from("18446744073709551615 0 1").unwrap();
+        let map = Map::new("a", "b", vec![range]);
+        assert_eq!(map.map(0), u64::MAX);
+        assert_eq!(map.map_reverse(u64::MAX), 0);
+
+        let err = Range::try_from("0 18446744073709551615 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 24: expected a range length that stays within u64, found `2`"
+        );
+        let err = Range::try_from("18446744073709551615 0 5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 24: expected a range length that stays within u64, found `5`"
+        );
+    }
+
+    #[test]
+    fn test_get_seeds_as_ranges() {
+        let ranges = get_seeds_as_ranges("seeds: 1 2 3 4").unwrap();
+        assert_eq!(ranges, vec![(1, 2), (3, 6),]);
+
+        let ranges = get_seeds_as_ranges("seeds: 1 2 3 4 5 6").unwrap();
+        assert_eq!(ranges, vec![(1, 2), (3, 6), (5, 10),]);
+
+        let err = get_seeds_as_ranges("seeds: 1 2 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 13: expected a range length, found the end of the line"
+        );
+    }
+
+    #[test]
+    fn test_get_seeds_as_ranges_bounds() {
+        // Empty ranges hold no seed
+        assert_eq!(get_seeds_as_ranges("seeds: 0 0").unwrap(), vec![]);
+        assert_eq!(get_seeds_as_ranges("seeds: 5 0 7 1").unwrap(), vec![(7, 7)]);
+
+        let ranges = get_seeds_as_ranges("seeds: 18446744073709551614 2").unwrap();
+        assert_eq!(ranges, vec![(u64::MAX - 1, u64::MAX)]);
+
+        let err = get_seeds_as_ranges("seeds: 18446744073709551614 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 29: expected a range length that stays within u64, found `3`"
+        );
+
+        let almanac = Almanac::parse(
+            "seeds: 5 0
+
+seed-to-soil map:
+1 2 3",
+        )
+        .unwrap();
+        assert_eq!(
+            almanac.part2(),
+            Err(SolveError::Unsolvable(
+                "there are no seed ranges".to_string()
+            ))
+        );
+    }
+}
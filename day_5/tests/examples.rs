@@ -0,0 +1,11 @@
+use common::Solution;
+use day_5::Almanac;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let almanac = Almanac::parse(EXAMPLE).unwrap();
+    assert_eq!(almanac.part1(), Ok(35));
+    assert_eq!(almanac.part2(), Ok(46));
+}
@@ -0,0 +1,205 @@
+//! Day 6: Wait For It
+//!
+//! Count the ways to beat the record distance of each boat race.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+use common::{parse_number, Location, ParseError, Solution, SolveError};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+const ACCELERATION: u64 = 1;
+
+/// The race sheet, read both as separate races and as one race with bad kerning
+#[derive(Debug)]
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    pub race: Race,
+}
+
+impl Solution for RaceSheet {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            races: parse_races(input)?,
+            race: parse_race(input)?,
+        })
+    }
+
+    // The product of the number of ways to win each race
+    fn part1(&self) -> Result<u64, SolveError> {
+        Ok(self.races.iter().map(nr_of_ways_to_win).product())
+    }
+
+    // The number of ways to win the race when the numbers are read as one race
+    fn part2(&self) -> Result<u64, SolveError> {
+        Ok(nr_of_ways_to_win(&self.race))
+    }
+}
+
+// The `Time:` and `Distance:` lines of the sheet
+fn sheet_lines(input: &str) -> Result<(&str, &str), ParseError> {
+    let mut lines = input.lines();
+    let time = lines.next().ok_or(ParseError::UnexpectedEnd {
+        expected: "a `Time:` line",
+        line: 1,
+    })?;
+    let distance = lines.next().ok_or(ParseError::UnexpectedEnd {
+        expected: "a `Distance:` line",
+        line: 2,
+    })?;
+
+    if let Some(line) = lines.next() {
+        return Err(
+            ParseError::unexpected("the end of the input", Location::of(line, line)).at_line(3),
+        );
+    }
+
+    Ok((time, distance))
+}
+
+// The numbers following the label at the start of the line
+fn strip_label<'a>(line: &'a str, label: &'static str) -> Result<&'a str, ParseError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::unexpected(label, Location::of(line, line)))
+}
+
+/// Parse the race sheet as a single race, ignoring the spaces between the numbers.
+///
+/// # Errors
+///
+/// Returns an error if the sheet is not a `Time:` line followed by a `Distance:` line.
+pub fn parse_race(input: &str) -> Result<Race, ParseError> {
+    let (time, distance) = sheet_lines(input)?;
+
+    Ok(Race {
+        time: parse_line_as_digits(time, strip_label(time, "Time:")?)?,
+        distance: parse_line_as_digits(distance, strip_label(distance, "Distance:")?)
+            .map_err(|err| err.at_line(2))?,
+    })
+}
+
+fn parse_line_as_digits(line: &str, input: &str) -> Result<u64, ParseError> {
+    let mut number: u64 = 0;
+    for (i, c) in input.char_indices() {
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+        let Some(digit) = c.to_digit(10) else {
+            let c = &input[i..i + c.len_utf8()];
+            return Err(ParseError::InvalidNumber(Location::of(line, c)));
+        };
+        number = number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add(u64::from(digit)))
+            .ok_or_else(|| ParseError::InvalidNumber(Location::of(line, input.trim())))?;
+    }
+    Ok(number)
+}
+
+/// Parse the race sheet as one race per column.
+///
+/// # Errors
+///
+/// Returns an error if the sheet is not a `Time:` line followed by a `Distance:` line with as
+/// many numbers.
+pub fn parse_races(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (time, distance) = sheet_lines(input)?;
+
+    let times = parse_numbers(time, "Time:")?;
+    let distances = parse_numbers(distance, "Distance:").map_err(|err| err.at_line(2))?;
+    if times.len() != distances.len() {
+        return Err(ParseError::unexpected(
+            "as many distances as times",
+            Location::of(distance, distance),
+        )
+        .at_line(2));
+    }
+
+    let iter = times.iter().zip(distances.iter());
+
+    Ok(iter
+        .map(|v| Race {
+            time: *v.0,
+            distance: *v.1,
+        })
+        .collect())
+}
+
+// The numbers of a line starting with the given label
+fn parse_numbers(line: &str, label: &'static str) -> Result<Vec<u64>, ParseError> {
+    strip_label(line, label)?
+        .split_whitespace()
+        .map(|number| parse_number(line, number))
+        .collect()
+}
+
+/// A race lasting `time` milliseconds, with the record `distance` in millimeters
+#[derive(Debug)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+/// The number of ways to hold the button that beat the record of the race
+#[must_use]
+pub fn nr_of_ways_to_win(race: &Race) -> u64 {
+    let optimal_time = time_for_max_distance(race.time);
+
+    let mut lower_bound = optimal_time;
+    let mut upper_bound = race.time;
+
+    // We start at the optimal time and do a binary search towards the max time to find the max winning value
+    let max_winning_value;
+    loop {
+        let time_to_test = u64::midpoint(lower_bound, upper_bound);
+
+        // We have found the maximum time to hold the acceleration button
+        if distance_traveled(race.time, time_to_test) <= race.distance {
+            upper_bound = time_to_test;
+        } else {
+            lower_bound = time_to_test;
+        }
+
+        if upper_bound - lower_bound <= 1 {
+            max_winning_value = upper_bound;
+            break;
+        }
+    }
+
+    lower_bound = 0;
+    upper_bound = optimal_time;
+    // Now we go down until we find the minumum time to hold the acceleration button
+    let min_winning_value;
+    loop {
+        let time_to_test = u64::midpoint(lower_bound, upper_bound);
+
+        // We have found the maximum time to hold the acceleration button
+        if distance_traveled(race.time, time_to_test) <= race.distance {
+            lower_bound = time_to_test;
+        } else {
+            upper_bound = time_to_test;
+        }
+
+        if upper_bound - lower_bound <= 1 {
+            min_winning_value = upper_bound;
+            break;
+        }
+    }
+
+    max_winning_value - min_winning_value
+}
+
+// This is the time that the acceleration button should be held down for it to travel the maximum distance
+// This is carculated using the derivative of the distance function to get the maximum of the distance function
+fn time_for_max_distance(max_time: u64) -> u64 {
+    max_time / 2
+}
+
+fn distance_traveled(max_time: u64, time_held: u64) -> u64 {
+    let speed = time_held * ACCELERATION;
+    let time_left = max_time - time_held;
+    speed * time_left
+}
@@ -0,0 +1,11 @@
+use common::Solution;
+use day_6::RaceSheet;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let sheet = RaceSheet::parse(EXAMPLE).unwrap();
+    assert_eq!(sheet.part1(), Ok(288));
+    assert_eq!(sheet.part2(), Ok(71503));
+}
@@ -0,0 +1,303 @@
+//! Day 7: Camel Cards
+//!
+//! Rank the hands of Camel Cards to find the total winnings.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+use common::{parse_number, Location, ParseError, Solution, SolveError, Unsolved};
+use std::cmp;
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The hands played in a game of Camel Cards, one hand and its bid per line
+#[derive(Debug)]
+pub struct CamelCards {
+    pub hands: Vec<Hand>,
+}
+
+impl Solution for CamelCards {
+    type Part1 = Unsolved;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let hands = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Hand::parse(line).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { hands })
+    }
+
+    fn part1(&self) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+
+    // Total winnings of all hands, with J as jokers
+    fn part2(&self) -> Result<u32, SolveError> {
+        let mut hands: Vec<&Hand> = self.hands.iter().collect();
+        hands.sort_by(|a, b| a.order_by_cards(b));
+
+        let mut winnings = 0;
+        for (rank, hand) in (1..).zip(&hands) {
+            winnings += hand.bid * rank;
+        }
+        Ok(winnings)
+    }
+}
+
+/// The type of a hand, ordered from weakest to strongest
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Type {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// A hand of five cards and its bid
+#[derive(Debug, PartialEq, Eq)]
+#[allow(clippy::struct_field_names)]
+pub struct Hand {
+    /// The value of each card, with jokers valued 0
+    pub cards: Vec<u32>,
+    pub bid: u32,
+    pub hand_type: Type,
+}
+
+impl Hand {
+    /// Parse a hand of the form `T55J5 684`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hand does not have five valid cards or the bid is malformed.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split_whitespace();
+        let (Some(cards), Some(bid)) = (parts.next(), parts.next()) else {
+            return Err(ParseError::unexpected(
+                "a hand and a bid",
+                Location::end_of(s),
+            ));
+        };
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::unexpected(
+                "the end of the line",
+                Location::of(s, extra),
+            ));
+        }
+
+        if cards.chars().count() != 5 {
+            return Err(ParseError::unexpected("five cards", Location::of(s, cards)));
+        }
+
+        let cards = parse_cards_in(s, cards)?;
+        let bid = parse_number(s, bid)?;
+        let hand_type = parse_type(&cards);
+
+        Ok(Self {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+
+    /// Order hands by their type, and then card by card
+    #[must_use]
+    pub fn order_by_cards(&self, other: &Self) -> cmp::Ordering {
+        if self.hand_type != other.hand_type {
+            return self.hand_type.cmp(&other.hand_type);
+        }
+
+        self.cards.cmp(&other.cards)
+    }
+}
+
+/// The type of the hand with the given cards, letting the jokers act as the most common card
+///
+/// # Panics
+///
+/// Panics if the hand does not have five cards.
+#[must_use]
+pub fn parse_type(cards: &[u32]) -> Type {
+    let mut counts = [0; 15];
+    let mut jokers = 0;
+    for card in cards {
+        if *card == 0 {
+            jokers += 1;
+            continue;
+        }
+        counts[*card as usize] += 1;
+    }
+
+    let mut counts = counts.into_iter().filter(|&c| c > 0).collect::<Vec<_>>();
+    counts.sort_by(|a, b| b.cmp(a));
+
+    if jokers == 5 {
+        return Type::FiveOfAKind;
+    }
+
+    counts[0] += jokers;
+
+    match counts.as_slice() {
+        [1, 1, 1, 1, 1] => Type::HighCard,
+        [2, 1, 1, 1] => Type::OnePair,
+        [2, 2, 1] => Type::TwoPair,
+        [3, 1, 1] => Type::ThreeOfAKind,
+        [3, 2] => Type::FullHouse,
+        [4, 1] => Type::FourOfAKind,
+        [5] => Type::FiveOfAKind,
+        _ => panic!("Invalid hand: {cards:?}"),
+    }
+}
+
+/// The values of the cards, with `J` as a joker valued 0
+///
+/// # Errors
+///
+/// Returns an error if a card is not a digit or one of `TJQKA`.
+pub fn parse_cards(s: &str) -> Result<Vec<u32>, ParseError> {
+    parse_cards_in(s, s)
+}
+
+// Parse cards that are part of `line`, so errors point at the right column of the line
+fn parse_cards_in(line: &str, s: &str) -> Result<Vec<u32>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| match c {
+            'T' => Ok(10),
+            'J' => Ok(0),
+            'Q' => Ok(12),
+            'K' => Ok(13),
+            'A' => Ok(14),
+            _ => c.to_digit(10).ok_or_else(|| {
+                ParseError::unexpected(
+                    "a digit or one of `TJQKA`",
+                    Location::of(line, &s[i..i + c.len_utf8()]),
+                )
+            }),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cards() {
+        let input = "87A9T";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(hand, vec![8, 7, 14, 9, 10]);
+
+        let input = "123456789TJQKA";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(hand, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 12, 13, 14]);
+    }
+
+    #[test]
+    fn test_parse_type() {
+        let input = "87A9T";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::HighCard);
+
+        let input = "55555";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::FiveOfAKind);
+
+        let input = "4444A";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::FourOfAKind);
+
+        let input = "33322";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::FullHouse);
+
+        let input = "22AAA";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::FullHouse);
+
+        let input = "12TTT";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::ThreeOfAKind);
+
+        let input = "225QQ";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::TwoPair);
+
+        let input = "22KJA";
+        let hand = parse_cards(input).unwrap();
+        assert_eq!(parse_type(&hand), Type::ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_parse_hand() {
+        let input = "87A9T 1";
+        let hand = Hand::parse(input).unwrap();
+        assert_eq!(
+            hand,
+            Hand {
+                cards: vec![8, 7, 14, 9, 10],
+                bid: 1,
+                hand_type: Type::HighCard,
+            }
+        );
+
+        let input = "88AAT 2";
+        let hand = Hand::parse(input).unwrap();
+        assert_eq!(
+            hand,
+            Hand {
+                cards: vec![8, 8, 14, 14, 10],
+                bid: 2,
+                hand_type: Type::TwoPair,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Hand::parse("87X9T 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a digit or one of `TJQKA`, found `X`"
+        );
+
+        let err = Hand::parse("87A9 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected five cards, found `87A9`"
+        );
+
+        let err = CamelCards::parse("87A9T 1\n88AAT").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a hand and a bid, found the end of the line"
+        );
+    }
+
+    #[test]
+    fn test_order_by_cards() {
+        let hand1 = Hand::parse("87A9T 1").unwrap();
+        let hand2 = Hand::parse("88AAT 2").unwrap();
+        assert_eq!(hand1.order_by_cards(&hand2), cmp::Ordering::Less);
+        assert_eq!(hand2.order_by_cards(&hand1), cmp::Ordering::Greater);
+
+        let hand1 = Hand::parse("87A9T 1").unwrap();
+        let hand2 = Hand::parse("87A9T 2").unwrap();
+        assert_eq!(hand1.order_by_cards(&hand2), cmp::Ordering::Equal);
+        assert_eq!(hand2.order_by_cards(&hand1), cmp::Ordering::Equal);
+
+        let hand1 = Hand::parse("87A9T 1").unwrap();
+        let hand2 = Hand::parse("87A9J 2").unwrap();
+        assert_eq!(hand1.order_by_cards(&hand2), cmp::Ordering::Less);
+        assert_eq!(hand2.order_by_cards(&hand1), cmp::Ordering::Greater);
+
+        let hand1 = Hand::parse("77777 1").unwrap();
+        let hand2 = Hand::parse("88888 1").unwrap();
+        assert_eq!(hand1.order_by_cards(&hand2), cmp::Ordering::Less);
+        assert_eq!(hand2.order_by_cards(&hand1), cmp::Ordering::Greater);
+    }
+}
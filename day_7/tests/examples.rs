@@ -0,0 +1,11 @@
+use common::{Solution, Unsolved};
+use day_7::CamelCards;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let game = CamelCards::parse(EXAMPLE).unwrap();
+    assert_eq!(game.part1(), Ok(Unsolved));
+    assert_eq!(game.part2(), Ok(5905));
+}
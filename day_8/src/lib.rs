@@ -0,0 +1,337 @@
+//! Day 8: Haunted Wasteland
+//!
+//! Follow the left/right instructions through the network of nodes.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+pub mod tree;
+
+use crate::tree::{Node, NodeRef};
+use common::{Location, ParseError, Solution, SolveError, Unsolved};
+use num::integer::lcm;
+use scan_fmt::scan_fmt_some;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The documents describing the network: the instructions and the nodes starting in A
+pub struct Network {
+    pub instructions: Vec<Instruction>,
+    /// The nodes ending in A, see [`parse_trees`]
+    pub trees: Vec<NodeRef<String>>,
+}
+
+impl Solution for Network {
+    type Part1 = Unsolved;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instructions: parse_instructions(input)?,
+            trees: parse_trees(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+
+    // Number of steps until all nodes ending in A are at a node ending in Z at the same time
+    fn part2(&self) -> Result<usize, SolveError> {
+        self.trees.iter().try_fold(1, |steps, node| {
+            Ok(lcm(steps, get_loop_size(node, &self.instructions)?))
+        })
+    }
+}
+
+/// The number of steps from the node to the first node ending in Z, repeating the
+/// instructions as often as needed
+///
+/// # Errors
+///
+/// Returns [`SolveError::Unsolvable`] if there are no instructions, or if a node on the way is
+/// missing the neighbour an instruction leads to.
+pub fn get_loop_size(
+    node: &NodeRef<String>,
+    instructions: &[Instruction],
+) -> Result<usize, SolveError> {
+    if instructions.is_empty() {
+        return Err(SolveError::Unsolvable(
+            "there are no instructions".to_string(),
+        ));
+    }
+
+    let mut current_node = Rc::clone(node);
+    let mut steps = 0;
+    while !RefCell::borrow(&current_node).val.ends_with('Z') {
+        let current_instruction = instructions[steps % instructions.len()];
+        current_node = step_through_node(&current_node, current_instruction)?;
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+/// The node reached by following the instruction from the current node
+///
+/// # Errors
+///
+/// Returns [`SolveError::Unsolvable`] if the node has no neighbour in the direction of the
+/// instruction.
+pub fn step_through_node(
+    current_node: &NodeRef<String>,
+    current_instruction: Instruction,
+) -> Result<NodeRef<String>, SolveError> {
+    let node = current_node.borrow();
+    let (next, side) = match current_instruction {
+        Instruction::Left => (&node.left, "left"),
+        Instruction::Right => (&node.right, "right"),
+    };
+    next.as_ref()
+        .map(Rc::clone)
+        .ok_or_else(|| SolveError::Unsolvable(format!("node {} has no {side} neighbour", node.val)))
+}
+
+/// Which neighbour of a node to step to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+/// Get the line of instructions from the input file.
+///
+/// # Errors
+///
+/// Returns an error if the input is empty, or the first line is empty or contains anything but
+/// `L` and `R`.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let line = input.lines().next().ok_or(ParseError::UnexpectedEnd {
+        expected: "a line of instructions",
+        line: 1,
+    })?;
+    if line.is_empty() {
+        return Err(ParseError::unexpected("`L` or `R`", Location::end_of(line)));
+    }
+
+    line.char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(ParseError::unexpected(
+                "`L` or `R`",
+                Location::of(line, &line[i..i + c.len_utf8()]),
+            )),
+        })
+        .collect()
+}
+
+/// Parse a node of the form `AAA = (BBB, CCC)` into the node and its left and right
+/// neighbours.
+///
+/// # Errors
+///
+/// Returns an error if the line is malformed.
+pub fn parse_line(line: &str) -> Result<(String, String, String), ParseError> {
+    match scan_fmt_some!(line, "{} = ({}, {})", String, String, String) {
+        (Some(node), Some(left), Some(right)) => Ok((node, left, right)),
+        _ => Err(ParseError::unexpected(
+            "a node such as `AAA = (BBB, CCC)`",
+            Location::of(line, line),
+        )),
+    }
+}
+
+/// Build the network from the node lines following the instructions, and return the nodes
+/// ending in A.
+///
+/// # Errors
+///
+/// Returns an error if a node line is malformed, or if a node leads to a node that is never
+/// defined.
+///
+/// # Panics
+///
+/// Panics if a node is missing from the network after it was inserted, which cannot happen.
+pub fn parse_trees(input: &str) -> Result<Vec<NodeRef<String>>, ParseError> {
+    let mut hash_map: HashMap<String, NodeRef<String>> = HashMap::new();
+    let mut start_nodes = Vec::new();
+    // The names of the defined nodes, and the error for each name a node leads to
+    let mut defined = HashSet::new();
+    let mut references = Vec::new();
+    for (i, line) in input.lines().enumerate().skip(2) {
+        let (node_name, left_name, right_name) =
+            parse_line(line).map_err(|err| err.at_line(i + 1))?;
+
+        defined.insert(node_name.clone());
+        let left_part = line.find('(').map_or(line, |start| &line[start + 1..]);
+        let right_part = left_part
+            .find(',')
+            .map_or(left_part, |comma| &left_part[comma + 1..]);
+        for (name, part) in [(&left_name, left_part), (&right_name, right_part)] {
+            let location = part.find(name.as_str()).map_or_else(
+                || Location::of(line, line),
+                |start| Location::of(line, &part[start..start + name.len()]),
+            );
+            let err = ParseError::unexpected("a node defined in the network", location);
+            references.push((name.clone(), err.at_line(i + 1)));
+        }
+
+        hash_map
+            .entry(node_name.clone())
+            .or_insert_with(|| Rc::new(RefCell::new(Node::new(node_name.clone()))));
+
+        hash_map
+            .entry(left_name.clone())
+            .or_insert_with(|| Rc::new(RefCell::new(Node::new(left_name.clone()))));
+
+        hash_map
+            .entry(right_name.clone())
+            .or_insert_with(|| Rc::new(RefCell::new(Node::new(right_name.clone()))));
+
+        let node = hash_map.get(&node_name).unwrap();
+        let left = hash_map.get(&left_name).unwrap();
+        let right = hash_map.get(&right_name).unwrap();
+
+        RefCell::borrow_mut(node).set_left(Rc::clone(left));
+        RefCell::borrow_mut(node).set_right(Rc::clone(right));
+
+        if node_name.ends_with('A') {
+            start_nodes.push(node_name);
+        }
+    }
+
+    if let Some((_, err)) = references
+        .into_iter()
+        .find(|(name, _)| !defined.contains(name))
+    {
+        return Err(err);
+    }
+
+    Ok(start_nodes
+        .iter()
+        .map(|node_name| Rc::clone(hash_map.get(node_name).unwrap()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instructions() {
+        let instructions = parse_instructions("RLRLLRR\n\ngibberish").unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Right,
+                Instruction::Left,
+                Instruction::Right,
+                Instruction::Left,
+                Instruction::Left,
+                Instruction::Right,
+                Instruction::Right,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_instructions("RLXR\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected `L` or `R`, found `X`"
+        );
+
+        let err = Network::parse("\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `L` or `R`, found the end of the line"
+        );
+
+        let err = parse_trees("RL\n\nAAA = (BBB, CCC)\nBBB -> CCC").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a node such as `AAA = (BBB, CCC)`, found `BBB -> CCC`"
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "AAA = (BBB, CCC)";
+        let (node, left, right) = parse_line(line).unwrap();
+        assert_eq!(node, "AAA");
+        assert_eq!(left, "BBB");
+        assert_eq!(right, "CCC");
+    }
+
+    #[test]
+    fn test_parse_tree() {
+        let input =
+            "instructions\n\nAAA = (B, C)\nB = (D, E)\nC = (C, C)\nD = (D, D)\nE = (E, E)\n";
+        let trees = parse_trees(input).unwrap();
+        let root = RefCell::borrow(&trees[0]);
+        assert_eq!(root.val, "AAA");
+        let left = RefCell::borrow(root.left.as_ref().unwrap());
+        assert_eq!(left.val, "B");
+        let right = RefCell::borrow(root.right.as_ref().unwrap());
+        assert_eq!(right.val, "C");
+
+        let left_left = RefCell::borrow(left.left.as_ref().unwrap());
+        assert_eq!(left_left.val, "D");
+        let left_right = RefCell::borrow(left.right.as_ref().unwrap());
+        assert_eq!(left_right.val, "E");
+
+        assert_eq!(RefCell::borrow(right.left.as_ref().unwrap()).val, "C");
+
+        let input = "instructions\n\nAAA = (BBB, CCC)\nBBB = (BBB, BBB)\nCCC = (AAA, CCC)";
+        let trees = parse_trees(input).unwrap();
+        let root = RefCell::borrow(&trees[0]);
+        assert_eq!(root.val, "AAA");
+        let left = RefCell::borrow(root.left.as_ref().unwrap());
+        assert_eq!(left.val, "BBB");
+        let right = RefCell::borrow(root.right.as_ref().unwrap());
+        assert_eq!(right.val, "CCC");
+
+        // Links from a node to itself are kept
+        assert_eq!(RefCell::borrow(left.left.as_ref().unwrap()).val, "BBB");
+        assert_eq!(RefCell::borrow(left.right.as_ref().unwrap()).val, "BBB");
+    }
+
+    #[test]
+    fn test_self_link() {
+        let network = Network::parse("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(network.part2(), Ok(2));
+    }
+
+    #[test]
+    fn test_undefined_node() {
+        let err = Network::parse("LR\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 8: expected a node defined in the network, found `BBB`"
+        );
+
+        let err = parse_trees("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, YYY)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 13: expected a node defined in the network, found `YYY`"
+        );
+
+        let node = Rc::new(RefCell::new(Node::new("AAA".to_string())));
+        assert_eq!(
+            get_loop_size(&node, &[Instruction::Left]),
+            Err(SolveError::Unsolvable(
+                "node AAA has no left neighbour".to_string()
+            ))
+        );
+        assert_eq!(
+            get_loop_size(&node, &[]),
+            Err(SolveError::Unsolvable(
+                "there are no instructions".to_string()
+            ))
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
 use std::{fmt, fmt::Formatter};
 
+/// A node in a binary tree that may contain cycles
 #[derive(Clone)]
 pub struct Node<T> {
     pub val: T,
@@ -9,6 +10,8 @@ pub struct Node<T> {
 }
 
 impl<T> Node<T> {
+    /// A node without any neighbours
+    #[must_use]
     pub fn new(val: T) -> Self {
         Node {
             val,
@@ -26,6 +29,7 @@ impl<T> Node<T> {
     }
 }
 
+/// A shared reference to a node
 pub type NodeRef<T> = Rc<RefCell<Node<T>>>;
 
 impl Debug for Node<String> {
@@ -0,0 +1,27 @@
+use common::{Solution, Unsolved};
+use day_8::Network;
+
+const EXAMPLE_1: &str = include_str!("../src/input_test_1.txt");
+const EXAMPLE_2: &str = include_str!("../src/input_test_2.txt");
+const EXAMPLE_PART2: &str = include_str!("../src/input_test_part2.txt");
+
+#[test]
+fn example_1() {
+    let network = Network::parse(EXAMPLE_1).unwrap();
+    assert_eq!(network.part1(), Ok(Unsolved));
+    assert_eq!(network.part2(), Ok(2));
+}
+
+#[test]
+fn example_2() {
+    let network = Network::parse(EXAMPLE_2).unwrap();
+    assert_eq!(network.part1(), Ok(Unsolved));
+    assert_eq!(network.part2(), Ok(6));
+}
+
+#[test]
+fn example_part2() {
+    let network = Network::parse(EXAMPLE_PART2).unwrap();
+    assert_eq!(network.part1(), Ok(Unsolved));
+    assert_eq!(network.part2(), Ok(6));
+}
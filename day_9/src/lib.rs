@@ -0,0 +1,157 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Extrapolate the histories of the oasis report in both directions.
+
+#![warn(clippy::all, clippy::pedantic)]
+
+use common::{parse_number, Location, ParseError, Solution, SolveError};
+
+/// The puzzle input bundled with the crate
+pub const DEFAULT_INPUT: &str = include_str!("input.txt");
+
+/// The report of value histories, one history per line
+#[derive(Debug)]
+pub struct Report {
+    pub histories: Vec<Vec<i32>>,
+}
+
+impl Solution for Report {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            histories: parse_histories(input)?,
+        })
+    }
+
+    // Sum of the next value of every history
+    fn part1(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .histories
+            .iter()
+            .map(|history| calculate_next(get_all_diffs(history.clone())))
+            .sum())
+    }
+
+    // Sum of the previous value of every history
+    fn part2(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .histories
+            .iter()
+            .map(|history| calculate_previous(&get_all_diffs(history.clone())))
+            .sum())
+    }
+}
+
+/// Parse the report, one history per line
+///
+/// # Errors
+///
+/// Returns an error if a line has no values or a value is not a number.
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_history(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Parse a history of whitespace separated values
+///
+/// # Errors
+///
+/// Returns an error if there are no values or a value is not a number.
+pub fn parse_history(input: &str) -> Result<Vec<i32>, ParseError> {
+    let history = input
+        .split_whitespace()
+        .map(|x| parse_number(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if history.is_empty() {
+        return Err(ParseError::unexpected("a value", Location::end_of(input)));
+    }
+    Ok(history)
+}
+
+/// The differences between each pair of consecutive values
+#[must_use]
+pub fn calculate_differences(history: &[i32]) -> Vec<i32> {
+    let mut differences = Vec::new();
+    for i in 0..history.len() - 1 {
+        differences.push(history[i + 1] - history[i]);
+    }
+    differences
+}
+
+/// Whether all values are the same
+#[must_use]
+pub fn is_constant(differences: &[i32]) -> bool {
+    differences.iter().all(|&x| x == differences[0])
+}
+
+/// The history followed by the differences of the previous sequence, until the
+/// differences are constant
+///
+/// # Panics
+///
+/// Panics if the history is empty.
+#[must_use]
+pub fn get_all_diffs(history: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut diffs = vec![history];
+    while !is_constant(diffs.last().unwrap()) {
+        let diff = calculate_differences(diffs.last().unwrap());
+        diffs.push(diff);
+    }
+
+    diffs
+}
+
+/// Extrapolate the value before the history from the output of [`get_all_diffs`]
+///
+/// # Panics
+///
+/// Panics if `diffs` is empty.
+#[must_use]
+pub fn calculate_previous(diffs: &[Vec<i32>]) -> i32 {
+    let mut diffs = diffs.to_vec();
+    for diff in &mut diffs {
+        diff.reverse();
+    }
+
+    for i in (0..diffs.len() - 1).rev() {
+        // The difference tp use when calculating the next value
+        let next_diff = diffs[i + 1].last().unwrap();
+
+        // The last element of the current vector
+        let current_last = diffs[i].last().unwrap();
+
+        let new_val = current_last - next_diff;
+        diffs[i].push(new_val);
+    }
+
+    // Return the last element of the first vector
+    diffs[0][diffs[0].len() - 1]
+}
+
+/// Extrapolate the value after the history from the output of [`get_all_diffs`]
+///
+/// # Panics
+///
+/// Panics if `diffs` is empty.
+#[must_use]
+pub fn calculate_next(mut diffs: Vec<Vec<i32>>) -> i32 {
+    for i in (0..diffs.len() - 1).rev() {
+        // The difference tp use when calculating the next value
+        let next_diff = diffs[i + 1].last().unwrap();
+
+        // The last element of the current vector
+        let current_last = diffs[i].last().unwrap();
+
+        let new_val = current_last + next_diff;
+        diffs[i].push(new_val);
+    }
+
+    // Return the last element of the first vector
+    diffs[0][diffs[0].len() - 1]
+}
@@ -0,0 +1,11 @@
+use common::Solution;
+use day_9::Report;
+
+const EXAMPLE: &str = include_str!("../src/input_test.txt");
+
+#[test]
+fn example() {
+    let report = Report::parse(EXAMPLE).unwrap();
+    assert_eq!(report.part1(), Ok(114));
+    assert_eq!(report.part2(), Ok(2));
+}